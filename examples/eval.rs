@@ -24,13 +24,11 @@ fn main() {
     let uwp_win = get_builtin_target_by_triple("i686-uwp-windows-msvc").unwrap();
     let mac = get_builtin_target_by_triple("x86_64-apple-darwin").unwrap();
 
-    let avail_targ_feats = ["fxsr", "sse", "sse2"];
-
     // This will satisfy all requirements
     assert!(specific.eval(|pred| {
         match pred {
             Predicate::Target(tp) => tp.matches(x86_win),
-            Predicate::TargetFeature(feat) => avail_targ_feats.contains(feat),
+            Predicate::TargetFeature(feat) => x86_win.target_features.contains(feat),
             Predicate::Feature(feat) => *feat == "cool_thing",
             _ => false,
         }
@@ -40,7 +38,7 @@ fn main() {
     assert!(!specific.eval(|pred| {
         match pred {
             Predicate::Target(tp) => tp.matches(x86_pentium_win),
-            Predicate::TargetFeature(feat) => avail_targ_feats.contains(feat),
+            Predicate::TargetFeature(feat) => x86_pentium_win.target_features.contains(feat),
             _ => false,
         }
     }));
@@ -49,7 +47,7 @@ fn main() {
     assert!(!specific.eval(|pred| {
         match pred {
             Predicate::Target(tp) => tp.matches(uwp_win),
-            Predicate::TargetFeature(feat) => avail_targ_feats.contains(feat),
+            Predicate::TargetFeature(feat) => uwp_win.target_features.contains(feat),
             _ => false,
         }
     }));
@@ -58,7 +56,7 @@ fn main() {
     assert!(!specific.eval(|pred| {
         match pred {
             Predicate::Target(tp) => tp.matches(mac),
-            Predicate::TargetFeature(feat) => avail_targ_feats.contains(feat),
+            Predicate::TargetFeature(feat) => mac.target_features.contains(feat),
             _ => false,
         }
     }));
@@ -2,6 +2,7 @@ use std::{error::Error, fmt};
 
 /// An error related to parsing of a cfg expression
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParseError {
     /// The string that was parsed
     pub original: String,
@@ -14,6 +15,8 @@ pub struct ParseError {
 
 /// The particular reason for a `ParseError`
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Reason {
     /// `not()` takes exactly 1 predicate, unlike `all()` and `any()`
     InvalidNot(usize),
@@ -31,7 +34,13 @@ pub enum Reason {
     Empty,
     /// Found an unexpected term, which wasn't one of the expected terms that
     /// is listed
-    Unexpected(&'static [&'static str]),
+    Unexpected(
+        #[cfg_attr(
+            feature = "serde",
+            serde(deserialize_with = "crate::serde_impls::expected")
+        )]
+        &'static [&'static str],
+    ),
     /// Failed to parse an integer value
     InvalidInteger,
     /// The root `cfg()` may only contain a single predicate
@@ -40,6 +49,8 @@ pub enum Reason {
     InvalidHasAtomic,
     /// An element was not part of the builtin information in rustc
     UnknownBuiltin,
+    /// A `version()` predicate didn't contain a valid `<major>.<minor>[.<patch>]` version
+    InvalidVersion,
 }
 
 impl fmt::Display for ParseError {
@@ -74,7 +85,7 @@ impl fmt::Display for ParseError {
 impl fmt::Display for Reason {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Reason::{
-            Empty, InvalidCharacters, InvalidHasAtomic, InvalidInteger, InvalidNot,
+            Empty, InvalidCharacters, InvalidHasAtomic, InvalidInteger, InvalidNot, InvalidVersion,
             MultipleRootPredicates, UnclosedParens, UnclosedQuotes, Unexpected, UnknownBuiltin,
             UnopenedParens, UnopenedQuotes,
         };
@@ -105,6 +116,7 @@ impl fmt::Display for Reason {
             MultipleRootPredicates => f.write_str("multiple root predicates"),
             InvalidHasAtomic => f.write_str("expected integer or \"ptr\""),
             UnknownBuiltin => f.write_str("unknown built-in"),
+            InvalidVersion => f.write_str("expected a version of the form \"<major>.<minor>\""),
         }
     }
 }
@@ -112,7 +124,7 @@ impl fmt::Display for Reason {
 impl Error for ParseError {
     fn description(&self) -> &str {
         use Reason::{
-            Empty, InvalidCharacters, InvalidHasAtomic, InvalidInteger, InvalidNot,
+            Empty, InvalidCharacters, InvalidHasAtomic, InvalidInteger, InvalidNot, InvalidVersion,
             MultipleRootPredicates, UnclosedParens, UnclosedQuotes, Unexpected, UnknownBuiltin,
             UnopenedParens, UnopenedQuotes,
         };
@@ -130,6 +142,7 @@ impl Error for ParseError {
             MultipleRootPredicates => "multiple root predicates",
             InvalidHasAtomic => "expected integer or \"ptr\"",
             UnknownBuiltin => "unknown built-in",
+            InvalidVersion => "invalid version",
         }
     }
 }
@@ -147,3 +160,363 @@ impl fmt::Display for HasAtomicParseError {
 }
 
 impl Error for HasAtomicParseError {}
+
+/// An error in a target specification JSON file, see
+/// [`TargetInfo::from_target_spec_json`](crate::targets::TargetInfo::from_target_spec_json)
+#[derive(Debug, PartialEq, Eq)]
+pub struct TargetSpecError {
+    /// The JSON that was parsed
+    pub original: String,
+    /// The range of characters in the original string that result in this
+    /// error
+    pub span: std::ops::Range<usize>,
+    /// The specific reason for the error
+    pub reason: TargetSpecReason,
+}
+
+/// The particular reason for a [`TargetSpecError`]
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TargetSpecReason {
+    /// Found a character that isn't valid JSON here, which wasn't one of the
+    /// expected terms that is listed
+    Unexpected(&'static [&'static str]),
+    /// Found more characters after the root object
+    TrailingCharacters,
+    /// An opening `{` was unmatched with a closing `}`
+    UnclosedObject,
+    /// An opening `[` was unmatched with a closing `]`
+    UnclosedArray,
+    /// An opening quote was unmatched with a closing quote
+    UnclosedString,
+    /// A string contains an escape sequence that isn't valid JSON, eg. `\q`
+    InvalidEscape,
+    /// The objects and arrays are nested more than 128 levels deep
+    DepthLimitExceeded,
+    /// A required field, eg. `arch`, is not specified
+    MissingField(&'static str),
+    /// The value is not of the expected type, eg. `string`
+    InvalidType(&'static str),
+    /// Failed to parse an integer value, or it is out of range
+    InvalidInteger,
+    /// The value is not one of the listed values
+    InvalidValue(&'static [&'static str]),
+}
+
+impl fmt::Display for TargetSpecReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TargetSpecReason::{
+            DepthLimitExceeded, InvalidEscape, InvalidInteger, InvalidType, InvalidValue,
+            MissingField, TrailingCharacters, UnclosedArray, UnclosedObject, UnclosedString,
+            Unexpected,
+        };
+
+        match self {
+            Unexpected(expected) | InvalidValue(expected) => Reason::Unexpected(expected).fmt(f),
+            TrailingCharacters => f.write_str("trailing characters"),
+            UnclosedObject => f.write_str("unclosed object"),
+            UnclosedArray => f.write_str("unclosed array"),
+            UnclosedString => f.write_str("unclosed string"),
+            InvalidEscape => f.write_str("invalid escape sequence"),
+            DepthLimitExceeded => f.write_str("nested too deeply"),
+            MissingField(field) => write!(f, "missing field `{field}`"),
+            InvalidType(expected) => write!(f, "invalid type, expected {expected}"),
+            InvalidInteger => f.write_str("invalid integer"),
+        }
+    }
+}
+
+impl fmt::Display for TargetSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (line, column) = self.diagnostic().line_column(self.span.start);
+        write!(f, "{} at {line}:{column}", self.reason)
+    }
+}
+
+impl Error for TargetSpecError {}
+
+impl TargetSpecError {
+    /// Creates a [`Diagnostic`] for this error
+    pub fn diagnostic(&self) -> Diagnostic {
+        Diagnostic {
+            original: self.original.clone(),
+            message: self.reason.to_string(),
+            primary: Label {
+                span: self.span.clone(),
+                message: String::new(),
+            },
+            secondary: Vec::new(),
+            suggestion: None,
+        }
+    }
+}
+
+/// A labelled span in a [`Diagnostic`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Label {
+    /// The range of characters in the original string
+    pub span: std::ops::Range<usize>,
+    /// The message shown next to the span, which may be empty
+    pub message: String,
+}
+
+/// A human readable description of a problem in a string, such as a
+/// [`ParseError`]
+///
+/// Unlike the `Display` of a [`ParseError`], this renders input with multiple
+/// lines, along with the line and column of the problem, and can point at
+/// other related spans and suggest a replacement.
+///
+/// ```
+/// let err = cfg_expr::Expression::parse(r#"all(
+///     unix,
+///     target_arc = "x86_64",
+/// )"#).unwrap_err();
+///
+/// let diag = err.diagnostic();
+/// assert_eq!(diag.suggestion.as_deref(), Some("target_arch"));
+/// assert_eq!(
+///     diag.to_string(),
+///     r#"error: unexpected `target_arc`
+///  --> 3:5
+///   |
+/// 3 |     target_arc = "x86_64",
+///   |     ^^^^^^^^^^ did you mean `target_arch`?
+/// "#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The string the problem is in
+    pub original: String,
+    /// A description of the problem
+    pub message: String,
+    /// The span of the problem
+    pub primary: Label,
+    /// Related spans that help explain the problem
+    pub secondary: Vec<Label>,
+    /// A replacement for the primary span that would likely fix the problem
+    pub suggestion: Option<String>,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic for a problem in a `key = "value"` predicate, with
+    /// a suggestion from the closest of the candidates
+    pub(crate) fn new<'a>(
+        original: &str,
+        message: String,
+        span: std::ops::Range<usize>,
+        candidates: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let suggestion = original
+            .get(span.clone())
+            .filter(|text| !text.is_empty())
+            .and_then(|text| closest(text, candidates))
+            .map(str::to_owned);
+
+        let mut secondary = Vec::new();
+        if let Some(key) = key_of_value(original, &span) {
+            secondary.push(Label {
+                span: key,
+                message: "for this key".to_owned(),
+            });
+        }
+
+        Self {
+            original: original.to_owned(),
+            message,
+            primary: Label {
+                span,
+                message: suggestion
+                    .as_ref()
+                    .map(|sugg| format!("did you mean `{sugg}`?"))
+                    .unwrap_or_default(),
+            },
+            secondary,
+            suggestion,
+        }
+    }
+
+    /// Gets the 1-based line and column, in characters, of a byte offset in
+    /// the original string
+    ///
+    /// An offset inside of a multibyte character is treated as the start of
+    /// that character.
+    pub fn line_column(&self, offset: usize) -> (usize, usize) {
+        let mut offset = offset.min(self.original.len());
+        while !self.original.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &self.original[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut annotations: Vec<_> = std::iter::once((&self.primary, '^'))
+            .chain(self.secondary.iter().map(|label| (label, '-')))
+            .collect();
+        annotations.sort_by_key(|(label, _)| label.span.start);
+
+        let mut lines: Vec<_> = annotations
+            .iter()
+            .map(|(label, _)| self.line_column(label.span.start).0)
+            .collect();
+        lines.sort_unstable();
+        lines.dedup();
+
+        let width = lines.last().map_or(1, |line| line.to_string().len());
+        let gutter = " ".repeat(width);
+
+        let (line, column) = self.line_column(self.primary.span.start);
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{gutter}--> {line}:{column}")?;
+        writeln!(f, "{gutter} |")?;
+
+        for line in lines {
+            let text = self.original.split('\n').nth(line - 1).unwrap_or_default();
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            writeln!(f, "{line:>width$} | {text}")?;
+
+            for (label, mark) in &annotations {
+                let (start_line, start) = self.line_column(label.span.start);
+                if start_line != line {
+                    continue;
+                }
+
+                // Spans that continue onto other lines are cut off at the end
+                // of the first one
+                let (end_line, end) = self.line_column(label.span.end);
+                let end = if end_line == line {
+                    end
+                } else {
+                    text.chars().count() + 1
+                };
+
+                write!(f, "{gutter} | {}", " ".repeat(start - 1))?;
+                for _ in 0..end.saturating_sub(start).max(1) {
+                    write!(f, "{mark}")?;
+                }
+
+                if label.message.is_empty() {
+                    writeln!(f)?;
+                } else {
+                    writeln!(f, " {}", label.message)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ParseError {
+    /// Creates a [`Diagnostic`] for this error
+    ///
+    /// If the error is an unexpected key or value, and it's close to one of
+    /// the expected terms, eg. `target_arc` instead of `target_arch`, that
+    /// term is suggested as a replacement. Likewise, an unknown builtin value
+    /// is compared against every value rustc knows of for its key, eg. `linux`
+    /// for `target_os = "linuxx"`.
+    pub fn diagnostic(&self) -> Diagnostic {
+        let builtins;
+        let candidates: &[&str] = match &self.reason {
+            Reason::Unexpected(expected) => expected,
+            Reason::UnknownBuiltin => {
+                builtins = key_of_value(&self.original, &self.span)
+                    .and_then(|key| self.original.get(key))
+                    .map(|key| crate::expr::builtin_values(key.strip_prefix("r#").unwrap_or(key)))
+                    .unwrap_or_default();
+                &builtins
+            }
+            _ => &[],
+        };
+
+        let mut diag = Diagnostic::new(
+            &self.original,
+            String::new(),
+            self.span.clone(),
+            candidates.iter().copied().filter(|term| {
+                term.chars()
+                    .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+            }),
+        );
+
+        diag.message = match &diag.suggestion {
+            Some(_) => format!("unexpected `{}`", &self.original[self.span.clone()]),
+            None => self.reason.to_string(),
+        };
+
+        // Only point at the parens or quotes themselves, rather than
+        // everything after or before them
+        if let Reason::UnclosedParens
+        | Reason::UnclosedQuotes
+        | Reason::UnopenedParens
+        | Reason::UnopenedQuotes = self.reason
+        {
+            let at = match self.reason {
+                Reason::UnopenedParens | Reason::UnopenedQuotes => self.span.end.saturating_sub(1),
+                _ => self.span.start,
+            };
+            diag.primary.span = at..(at + 1).min(self.original.len());
+            diag.secondary.clear();
+        }
+
+        diag
+    }
+}
+
+/// Finds the span of the key in a `key = "value"`, where `span` is the span of
+/// the value without its quotes
+fn key_of_value(original: &str, span: &std::ops::Range<usize>) -> Option<std::ops::Range<usize>> {
+    let before = original.get(..span.start)?.strip_suffix('"')?;
+    let before = before.trim_end().strip_suffix('=')?.trim_end();
+    let key = before
+        .char_indices()
+        .rfind(|(_, c)| !(c.is_alphanumeric() || *c == '_' || *c == '#'))
+        .map_or(0, |(i, c)| i + c.len_utf8());
+
+    (key < before.len()).then_some(key..before.len())
+}
+
+/// Finds the candidate closest to the text, if it's close enough to likely be
+/// a typo
+pub(crate) fn closest<'a>(
+    text: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let text = text.to_lowercase();
+    let max = text.chars().count().max(3) / 3;
+
+    candidates
+        .into_iter()
+        .filter(|cand| *cand != text)
+        .map(|cand| (distance(&text, &cand.to_lowercase()), cand))
+        .filter(|(dist, _)| *dist <= max)
+        .min_by_key(|(dist, _)| *dist)
+        .map(|(_, cand)| cand)
+}
+
+/// The Levenshtein distance between two strings
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ac) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, bc) in b.iter().enumerate() {
+            let sub = prev[j] + usize::from(ac != *bc);
+            cur[j + 1] = sub.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+
+    prev[b.len()]
+}
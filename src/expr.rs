@@ -1,5 +1,20 @@
+mod analysis;
+mod builder;
+mod check_cfg;
+mod context;
 pub mod lexer;
 mod parser;
+mod recover;
+mod sat;
+mod simplify;
+mod tree;
+
+pub use analysis::{BuiltinMatch, Trivial, Triviality};
+pub use check_cfg::{CheckCfg, ExpectedValues, UnexpectedCfg, UnexpectedKind};
+pub use context::{CompilationContext, EvalResult, Profile};
+pub use parser::{Dialect, ParseOptions};
+pub use recover::Recovered;
+pub use tree::CfgTree;
 
 use smallvec::SmallVec;
 use std::ops::Range;
@@ -28,7 +43,8 @@ pub enum Func {
 use crate::targets as targ;
 
 /// All predicates that pertains to a target, except for `target_feature`
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[non_exhaustive]
 pub enum TargetPredicate {
     /// [target_abi](https://github.com/rust-lang/rust/issues/80970)
     Abi(targ::Abi),
@@ -44,6 +60,12 @@ pub enum TargetPredicate {
     Family(targ::Family),
     /// [target_has_atomic](https://doc.rust-lang.org/reference/conditional-compilation.html#target_has_atomic).
     HasAtomic(targ::HasAtomic),
+    /// [target_has_atomic_load_store](https://github.com/rust-lang/rust/issues/94039),
+    /// atomic loads and stores are supported, even if compare and swap isn't
+    HasAtomicLoadStore(targ::HasAtomic),
+    /// [target_has_atomic_equal_alignment](https://github.com/rust-lang/rust/issues/93822),
+    /// the atomic type has the same alignment as the equivalent integer
+    HasAtomicEqualAlignment(targ::HasAtomic),
     /// [target_os](https://doc.rust-lang.org/reference/conditional-compilation.html#target_os)
     Os(targ::Os),
     /// [panic](https://doc.rust-lang.org/reference/conditional-compilation.html#panic)
@@ -52,16 +74,39 @@ pub enum TargetPredicate {
     PointerWidth(u8),
     /// [target_vendor](https://doc.rust-lang.org/reference/conditional-compilation.html#target_vendor)
     Vendor(targ::Vendor),
+    /// [target_thread_local](https://github.com/rust-lang/rust/issues/29594),
+    /// the `#[thread_local]` attribute is supported
+    ThreadLocal,
+    /// `target_has_reliable_f16`, the `f16` type can be used without
+    /// miscompilations or linker errors
+    HasReliableF16,
+    /// `target_has_reliable_f16_math`, the math functions for `f16` are
+    /// available
+    HasReliableF16Math,
+    /// `target_has_reliable_f128`, the `f128` type can be used without
+    /// miscompilations or linker errors
+    HasReliableF128,
+    /// `target_has_reliable_f128_math`, the math functions for `f128` are
+    /// available
+    HasReliableF128Math,
 }
 
 pub trait TargetMatcher {
     fn matches(&self, tp: &TargetPredicate) -> bool;
 }
 
+impl<T: TargetMatcher + ?Sized> TargetMatcher for &T {
+    fn matches(&self, tp: &TargetPredicate) -> bool {
+        (**self).matches(tp)
+    }
+}
+
 impl TargetMatcher for targ::TargetInfo {
     fn matches(&self, tp: &TargetPredicate) -> bool {
         use TargetPredicate::{
-            Abi, Arch, Endian, Env, Family, HasAtomic, Os, Panic, PointerWidth, Vendor,
+            Abi, Arch, Endian, Env, Family, HasAtomic, HasAtomicEqualAlignment, HasAtomicLoadStore,
+            HasReliableF16, HasReliableF16Math, HasReliableF128, HasReliableF128Math, Os, Panic,
+            PointerWidth, ThreadLocal, Vendor,
         };
 
         match tp {
@@ -79,6 +124,10 @@ impl TargetMatcher for targ::TargetInfo {
             },
             Family(fam) => self.families.contains(fam),
             HasAtomic(has_atomic) => self.has_atomics.contains(*has_atomic),
+            HasAtomicLoadStore(has_atomic) => self.has_atomics_load_store.contains(*has_atomic),
+            HasAtomicEqualAlignment(has_atomic) => {
+                self.has_atomics_equal_alignment.contains(*has_atomic)
+            }
             Os(os) => match &self.os {
                 Some(self_os) => os == self_os,
                 // os = "none" means it should be matched against None. Note that this is different
@@ -91,6 +140,11 @@ impl TargetMatcher for targ::TargetInfo {
                 None => ven == &targ::Vendor::unknown,
             },
             Panic(panic) => &self.panic == panic,
+            ThreadLocal => self.thread_local,
+            HasReliableF16 => self.reliable_floats.f16,
+            HasReliableF16Math => self.reliable_floats.f16_math,
+            HasReliableF128 => self.reliable_floats.f128,
+            HasReliableF128Math => self.reliable_floats.f128_math,
         }
     }
 }
@@ -101,7 +155,9 @@ impl TargetMatcher for target_lexicon::Triple {
     #[allow(clippy::match_same_arms)]
     fn matches(&self, tp: &TargetPredicate) -> bool {
         use TargetPredicate::{
-            Abi, Arch, Endian, Env, Family, HasAtomic, Os, Panic, PointerWidth, Vendor,
+            Abi, Arch, Endian, Env, Family, HasAtomic, HasAtomicEqualAlignment, HasAtomicLoadStore,
+            HasReliableF16, HasReliableF16Math, HasReliableF128, HasReliableF128Math, Os, Panic,
+            PointerWidth, ThreadLocal, Vendor,
         };
         use target_lexicon::{
             self as tl, Architecture as arch, ArmArchitecture, Endianness as endian,
@@ -329,7 +385,7 @@ impl TargetMatcher for target_lexicon::Triple {
                     _ => false,
                 }
             }
-            HasAtomic(_) => {
+            HasAtomic(_) | HasAtomicLoadStore(_) | HasAtomicEqualAlignment(_) => {
                 // atomic support depends on both the architecture and the OS. Assume false for
                 // this.
                 false
@@ -369,6 +425,13 @@ impl TargetMatcher for target_lexicon::Triple {
                 // panic support depends on the OS. Assume false for this.
                 false
             }
+            ThreadLocal | HasReliableF16 | HasReliableF16Math | HasReliableF128
+            | HasReliableF128Math => {
+                // These depend on the target specification and the codegen
+                // backend, neither of which are part of the triple. Assume
+                // false for these.
+                false
+            }
             Vendor(ven) => match ven.0.parse::<target_lexicon::Vendor>() {
                 Ok(v) => {
                     if self.vendor == v
@@ -408,7 +471,8 @@ impl TargetPredicate {
     /// Returns true of the predicate matches the specified target
     ///
     /// Note that when matching against a [`target_lexicon::Triple`], the
-    /// `has_target_atomic` and `panic` predicates will _always_ return `false`.
+    /// `has_target_atomic*`, `panic`, `target_thread_local`, and
+    /// `target_has_reliable_*` predicates will _always_ return `false`.
     ///
     /// ```
     /// use cfg_expr::{targets::*, expr::TargetPredicate as tp};
@@ -432,6 +496,46 @@ impl TargetPredicate {
     }
 }
 
+/// Displays the predicate in its canonical `cfg()` form, eg. `target_os = "linux"`
+///
+/// The `unix` and `windows` families are displayed as the bare predicates.
+impl std::fmt::Display for TargetPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use TargetPredicate::{
+            Abi, Arch, Endian, Env, Family, HasAtomic, HasAtomicEqualAlignment, HasAtomicLoadStore,
+            HasReliableF16, HasReliableF16Math, HasReliableF128, HasReliableF128Math, Os, Panic,
+            PointerWidth, ThreadLocal, Vendor,
+        };
+
+        match self {
+            Abi(abi) => write!(f, "target_abi = \"{abi}\""),
+            Arch(arch) => write!(f, "target_arch = \"{arch}\""),
+            Endian(end) => write!(f, "target_endian = \"{end}\""),
+            Env(env) => write!(f, "target_env = \"{env}\""),
+            Family(fam) if fam == &targ::Family::unix || fam == &targ::Family::windows => {
+                f.write_str(fam.as_str())
+            }
+            Family(fam) => write!(f, "target_family = \"{fam}\""),
+            HasAtomic(has_atomic) => write!(f, "target_has_atomic = \"{has_atomic}\""),
+            HasAtomicLoadStore(has_atomic) => {
+                write!(f, "target_has_atomic_load_store = \"{has_atomic}\"")
+            }
+            HasAtomicEqualAlignment(has_atomic) => {
+                write!(f, "target_has_atomic_equal_alignment = \"{has_atomic}\"")
+            }
+            Os(os) => write!(f, "target_os = \"{os}\""),
+            Panic(panic) => write!(f, "panic = \"{panic}\""),
+            PointerWidth(pw) => write!(f, "target_pointer_width = \"{pw}\""),
+            Vendor(ven) => write!(f, "target_vendor = \"{ven}\""),
+            ThreadLocal => f.write_str("target_thread_local"),
+            HasReliableF16 => f.write_str("target_has_reliable_f16"),
+            HasReliableF16Math => f.write_str("target_has_reliable_f16_math"),
+            HasReliableF128 => f.write_str("target_has_reliable_f128"),
+            HasReliableF128Math => f.write_str("target_has_reliable_f128_math"),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) enum Which {
     Abi,
@@ -441,9 +545,16 @@ pub(crate) enum Which {
     Family,
     Os,
     HasAtomic(targ::HasAtomic),
+    HasAtomicLoadStore(targ::HasAtomic),
+    HasAtomicEqualAlignment(targ::HasAtomic),
     Panic,
     PointerWidth(u8),
     Vendor,
+    ThreadLocal,
+    HasReliableF16,
+    HasReliableF16Math,
+    HasReliableF128,
+    HasReliableF128Math,
 }
 
 #[derive(Clone, Debug)]
@@ -453,7 +564,8 @@ pub(crate) struct InnerTarget {
 }
 
 /// A single predicate in a `cfg()` expression
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Predicate<'a> {
     /// A target predicate, with the `target_` prefix
     Target(TargetPredicate),
@@ -465,14 +577,510 @@ pub enum Predicate<'a> {
     /// [Enabled](https://doc.rust-lang.org/reference/conditional-compilation.html#proc_macro) for
     /// crates of the `proc_macro` type.
     ProcMacro,
+    /// `overflow_checks`, enabled when arithmetic overflow checks are, eg.
+    /// with `-C overflow-checks`
+    OverflowChecks,
+    /// `ub_checks`, enabled when the standard library checks for undefined
+    /// behavior, eg. with `-Z ub-checks`
+    UbChecks,
+    /// [Enabled](https://doc.rust-lang.org/rustdoc/write-documentation/documentation-tests.html#include-items-only-when-collecting-doctests)
+    /// when rustdoc is collecting doctests.
+    Doctest,
+    /// `miri`, enabled when running under [Miri](https://github.com/rust-lang/miri)
+    Miri,
+    /// `contract_checks`, enabled when contracts are checked, eg. with
+    /// `-Z contract-checks`
+    ContractChecks,
+    /// `sanitize = "<sanitizer>"`, enabled for each sanitizer from
+    /// `-Z sanitizer`
+    Sanitize(Sanitizer),
+    /// `fmt_debug = "<level>"`, the amount of `Debug` output from
+    /// `-Z fmt-debug`
+    FmtDebug(FmtDebug),
+    /// `relocation_model = "<model>"`, the relocation model from
+    /// `-C relocation-model`, or the target's default
+    RelocationModel(RelocationModel),
     /// A [`feature = "<name>"`](https://doc.rust-lang.org/nightly/cargo/reference/features.html)
     Feature(&'a str),
     /// [target_feature](https://doc.rust-lang.org/reference/conditional-compilation.html#target_feature)
     TargetFeature(&'a str),
     /// A generic bare predicate key that doesn't match one of the known options, eg `cfg(bare)`
+    ///
+    /// Raw identifiers don't keep their `r#` prefix, eg `cfg(r#foo)` is the
+    /// same as `cfg(foo)`, see [`Predicate::is_raw`]
     Flag(&'a str),
     /// A generic key = "value" predicate that doesn't match one of the known options, eg `cfg(foo = "bar")`
+    ///
+    /// Raw identifiers don't keep their `r#` prefix, eg `cfg(r#foo = "bar")`
+    /// is the same as `cfg(foo = "bar")`, see [`Predicate::is_raw`]
     KeyValue { key: &'a str, val: &'a str },
+    /// A [`version("<version>")`](https://github.com/rust-lang/rust/issues/64796)
+    /// predicate, which is true if rustc is at least the specified version
+    Version {
+        /// The minimum rustc version
+        version: RustcVersion,
+        /// The span of the version string in the original expression
+        span: Range<usize>,
+    },
+}
+
+/// A predicate along with its location in an expression, see
+/// [`Expression::predicates_with_spans`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpannedPredicate<'a> {
+    /// The predicate
+    pub predicate: Predicate<'a>,
+    /// The span of the whole predicate, eg. `target_os = "linux"` or
+    /// `version("1.80")`
+    pub span: Range<usize>,
+    /// The span of the key, eg. `target_os`, including the `r#` prefix of a
+    /// raw identifier
+    pub key: Range<usize>,
+    /// The span of the value without its quotes, eg. `linux`, if the
+    /// predicate has one
+    pub value: Option<Range<usize>>,
+    /// The functions the predicate is nested in, from the innermost to the
+    /// outermost
+    pub path: Vec<Func>,
+}
+
+impl Predicate<'_> {
+    /// Returns true if this is a [`Predicate::Flag`] or [`Predicate::KeyValue`]
+    /// whose key can only be written as a [raw identifier](https://doc.rust-lang.org/reference/identifiers.html#raw-identifiers),
+    /// eg. `r#true` or `r#fn`, which is how it is displayed
+    ///
+    /// The `r#` prefix is not part of the key, so `r#foo` and `foo` are the
+    /// same predicate, and raw identifiers for the known options, eg.
+    /// `r#unix`, are parsed the same as their non-raw equivalent. This only
+    /// depends on the key, the keys that can't be used otherwise are the
+    /// `true` and `false` literals, `all`, `any` and `not`, and Rust's
+    /// keywords. Whether the expression used the prefix can be seen from the
+    /// key span of [`Expression::predicates_with_spans`], which includes it.
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::parse("any(r#true, r#unix, r#foo, r#fn)").unwrap();
+    /// let preds: Vec<_> = expr.predicates().collect();
+    ///
+    /// assert_eq!(preds[0], Predicate::Flag("true"));
+    /// assert!(preds[0].is_raw());
+    /// assert_eq!(preds[0].to_string(), "r#true");
+    /// assert!(matches!(preds[1], Predicate::Target(_)));
+    /// assert_eq!(preds[2], Predicate::Flag("foo"));
+    /// assert!(!preds[2].is_raw());
+    /// assert!(preds[3].is_raw());
+    ///
+    /// let spans: Vec<_> = expr.predicates_with_spans().collect();
+    /// assert_eq!(&expr.original()[spans[2].key.clone()], "r#foo");
+    /// ```
+    pub fn is_raw(&self) -> bool {
+        // The strict and reserved keywords, other than `crate`, `self`,
+        // `Self` and `super`, which can't be raw identifiers
+        const KEYWORDS: &[&str] = &[
+            "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+            "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+            "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override",
+            "priv", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type",
+            "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+        ];
+
+        match self {
+            Self::Flag(key) | Self::KeyValue { key, .. } => {
+                matches!(*key, "all" | "any" | "not") || KEYWORDS.contains(key)
+            }
+            _ => false,
+        }
+    }
+
+    /// Copies any borrowed strings, so that the predicate no longer borrows
+    /// from the [`Expression`] it was retrieved from
+    #[inline]
+    pub fn into_owned(self) -> OwnedPredicate {
+        self.into()
+    }
+}
+
+/// Displays the predicate in its canonical `cfg()` form, eg. `feature = "foo"`
+///
+/// Note that the cfg syntax has no way to escape quotes, so a value that
+/// contains a `"` can't be parsed again.
+impl std::fmt::Display for Predicate<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Target(tp) => tp.fmt(f),
+            Self::Test => f.write_str("test"),
+            Self::DebugAssertions => f.write_str("debug_assertions"),
+            Self::ProcMacro => f.write_str("proc_macro"),
+            Self::OverflowChecks => f.write_str("overflow_checks"),
+            Self::UbChecks => f.write_str("ub_checks"),
+            Self::Doctest => f.write_str("doctest"),
+            Self::Miri => f.write_str("miri"),
+            Self::ContractChecks => f.write_str("contract_checks"),
+            Self::Sanitize(san) => write!(f, "sanitize = \"{san}\""),
+            Self::FmtDebug(fd) => write!(f, "fmt_debug = \"{fd}\""),
+            Self::RelocationModel(rm) => write!(f, "relocation_model = \"{rm}\""),
+            Self::Feature(feat) => write!(f, "feature = \"{feat}\""),
+            Self::TargetFeature(feat) => write!(f, "target_feature = \"{feat}\""),
+            Self::Flag(flag) => {
+                if self.is_raw() {
+                    f.write_str("r#")?;
+                }
+                f.write_str(flag)
+            }
+            Self::KeyValue { key, val } => {
+                if self.is_raw() {
+                    f.write_str("r#")?;
+                }
+                write!(f, "{key} = \"{val}\"")
+            }
+            Self::Version { version, .. } => write!(f, "version(\"{version}\")"),
+        }
+    }
+}
+
+/// A rustc version, as used by [`Predicate::Version`]
+///
+/// The version must be of the form `<major>.<minor>` or
+/// `<major>.<minor>.<patch>`, a missing patch version is treated as 0. Like
+/// rustc, pre-release and build metadata are not allowed.
+///
+/// ```
+/// use cfg_expr::expr::RustcVersion;
+///
+/// let version: RustcVersion = "1.80".parse().unwrap();
+/// assert_eq!(version, RustcVersion { major: 1, minor: 80, patch: 0 });
+///
+/// assert!(version.matches(&"1.80.1".parse().unwrap()));
+/// assert!(!version.matches(&"1.79.0".parse().unwrap()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RustcVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl RustcVersion {
+    /// Determines if a `version()` predicate with this version is true for
+    /// the specified rustc version, ie. `rustc` is at least this version
+    #[inline]
+    pub fn matches(&self, rustc: &RustcVersion) -> bool {
+        rustc >= self
+    }
+}
+
+/// The version of rustc the builtin targets were retrieved from, see
+/// [`targ::rustc_version`]
+impl Default for RustcVersion {
+    fn default() -> Self {
+        targ::rustc_version()
+            .parse()
+            .expect("the builtin rustc version is valid")
+    }
+}
+
+impl std::str::FromStr for RustcVersion {
+    type Err = crate::error::Reason;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let num = |part: &str| {
+            // Disallow signs, which are accepted by the integer parsing
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+
+            part.parse::<u16>().ok()
+        };
+
+        let mut parts = s.split('.');
+        let major = parts.next().and_then(num);
+        let minor = parts.next().and_then(num);
+        let patch = parts.next().map_or(Some(0), num);
+
+        match (major, minor, patch, parts.next()) {
+            (Some(major), Some(minor), Some(patch), None) => Ok(Self {
+                major,
+                minor,
+                patch,
+            }),
+            _ => Err(crate::error::Reason::InvalidVersion),
+        }
+    }
+}
+
+impl std::fmt::Display for RustcVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Implements an enum for a predicate that only has a fixed set of values,
+/// which may contain characters that aren't valid in identifiers
+macro_rules! value_enum {
+    (
+        $(#[$outer:meta])*
+        pub enum $kind:ident {
+            $(
+                $(#[$inner:meta])*
+                $name:ident = $value:literal,
+            )+
+        }
+    ) => {
+        $(#[$outer])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $kind {
+            $(
+                $(#[$inner])*
+                $name,
+            )+
+        }
+
+        impl $kind {
+            /// Every value, in the order they are declared
+            pub const ALL: &'static [Self] = &[$(Self::$name,)+];
+
+            /// The value as it appears in a `cfg()` expression
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$name => $value,)+
+                }
+            }
+        }
+
+        impl std::str::FromStr for $kind {
+            type Err = crate::error::Reason;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($value => Ok(Self::$name),)+
+                    _ => Err(crate::error::Reason::Unexpected(&[$($value,)+])),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $kind {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+value_enum! {
+    /// A sanitizer, as used by [`Predicate::Sanitize`]
+    ///
+    /// ```
+    /// use cfg_expr::{expr::Sanitizer, Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"sanitize = "shadow-call-stack""#).unwrap();
+    /// assert_eq!(
+    ///     expr.predicates().next(),
+    ///     Some(Predicate::Sanitize(Sanitizer::ShadowCallStack))
+    /// );
+    ///
+    /// assert!(Expression::parse(r#"sanitize = "shadow""#).is_err());
+    /// ```
+    pub enum Sanitizer {
+        Address = "address",
+        Cfi = "cfi",
+        Dataflow = "dataflow",
+        Hwaddress = "hwaddress",
+        Kcfi = "kcfi",
+        KernelAddress = "kernel-address",
+        KernelHwaddress = "kernel-hwaddress",
+        Leak = "leak",
+        Memory = "memory",
+        Memtag = "memtag",
+        Realtime = "realtime",
+        Safestack = "safestack",
+        ShadowCallStack = "shadow-call-stack",
+        Thread = "thread",
+    }
+}
+
+value_enum! {
+    /// The amount of `Debug` output, as used by [`Predicate::FmtDebug`]
+    pub enum FmtDebug {
+        /// The derived `Debug` implementations print everything
+        Full = "full",
+        /// The derived `Debug` implementations only print the type's name
+        Shallow = "shallow",
+        /// `Debug` implementations print nothing
+        None = "none",
+    }
+}
+
+value_enum! {
+    /// A relocation model, as used by [`Predicate::RelocationModel`]
+    pub enum RelocationModel {
+        Static = "static",
+        Pic = "pic",
+        Pie = "pie",
+        DynamicNoPic = "dynamic-no-pic",
+        Ropi = "ropi",
+        Rwpi = "rwpi",
+        RopiRwpi = "ropi-rwpi",
+    }
+}
+
+/// Every value rustc knows of for a builtin cfg key, eg. each `target_os` of
+/// the builtin targets, or an empty list for other keys
+pub(crate) fn builtin_values(key: &str) -> Vec<&'static str> {
+    use targ::ALL_BUILTINS;
+
+    let mut values: Vec<&'static str> = match key {
+        "sanitize" => Sanitizer::ALL.iter().map(|v| v.as_str()).collect(),
+        "fmt_debug" => FmtDebug::ALL.iter().map(|v| v.as_str()).collect(),
+        "relocation_model" => RelocationModel::ALL.iter().map(|v| v.as_str()).collect(),
+        "target_endian" => vec!["big", "little"],
+        "target_family" => ALL_BUILTINS
+            .iter()
+            .flat_map(|ti| ti.families.iter().map(|fam| fam.as_str()))
+            .collect(),
+        _ => ALL_BUILTINS
+            .iter()
+            .filter_map(|ti| {
+                Some(match key {
+                    "target_arch" => ti.arch.as_str(),
+                    "target_os" => ti.os.as_ref().map_or("none", |os| os.as_str()),
+                    "target_env" => ti.env.as_ref().map_or("", |env| env.as_str()),
+                    "target_abi" => ti.abi.as_ref().map_or("", |abi| abi.as_str()),
+                    "target_vendor" => ti.vendor.as_ref().map_or("unknown", |v| v.as_str()),
+                    "panic" => ti.panic.as_str(),
+                    _ => return None,
+                })
+            })
+            .collect(),
+    };
+
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+/// An owned version of [`Predicate`], which doesn't borrow from the
+/// [`Expression`] it was retrieved from
+///
+/// Unlike [`Predicate`], this implements [`Hash`] and [`Ord`], so it can be
+/// used as the key of a map or set.
+///
+/// ```
+/// use cfg_expr::{expr::OwnedPredicate, Expression};
+/// use std::collections::BTreeMap;
+///
+/// let mut users = BTreeMap::<OwnedPredicate, Vec<&str>>::new();
+///
+/// for (name, cfg) in [("a", "any(unix, feature = \"std\")"), ("b", "all(unix, test)")] {
+///     for pred in Expression::parse(cfg).unwrap().owned_predicates() {
+///         users.entry(pred).or_default().push(name);
+///     }
+/// }
+///
+/// let unix = Expression::parse("unix").unwrap().owned_predicates().next().unwrap();
+/// assert_eq!(users[&unix], ["a", "b"]);
+/// assert_eq!(users.len(), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum OwnedPredicate {
+    /// See [`Predicate::Target`]
+    Target(TargetPredicate),
+    /// See [`Predicate::Test`]
+    Test,
+    /// See [`Predicate::DebugAssertions`]
+    DebugAssertions,
+    /// See [`Predicate::ProcMacro`]
+    ProcMacro,
+    /// See [`Predicate::OverflowChecks`]
+    OverflowChecks,
+    /// See [`Predicate::UbChecks`]
+    UbChecks,
+    /// See [`Predicate::Doctest`]
+    Doctest,
+    /// See [`Predicate::Miri`]
+    Miri,
+    /// See [`Predicate::ContractChecks`]
+    ContractChecks,
+    /// See [`Predicate::Sanitize`]
+    Sanitize(Sanitizer),
+    /// See [`Predicate::FmtDebug`]
+    FmtDebug(FmtDebug),
+    /// See [`Predicate::RelocationModel`]
+    RelocationModel(RelocationModel),
+    /// See [`Predicate::Feature`]
+    Feature(String),
+    /// See [`Predicate::TargetFeature`]
+    TargetFeature(String),
+    /// See [`Predicate::Flag`]
+    Flag(String),
+    /// See [`Predicate::KeyValue`]
+    KeyValue { key: String, val: String },
+    /// See [`Predicate::Version`]
+    Version(RustcVersion),
+}
+
+impl OwnedPredicate {
+    /// Borrows the owned predicate as a [`Predicate`]
+    pub fn as_predicate(&self) -> Predicate<'_> {
+        match self {
+            Self::Target(tp) => Predicate::Target(tp.clone()),
+            Self::Test => Predicate::Test,
+            Self::DebugAssertions => Predicate::DebugAssertions,
+            Self::ProcMacro => Predicate::ProcMacro,
+            Self::OverflowChecks => Predicate::OverflowChecks,
+            Self::UbChecks => Predicate::UbChecks,
+            Self::Doctest => Predicate::Doctest,
+            Self::Miri => Predicate::Miri,
+            Self::ContractChecks => Predicate::ContractChecks,
+            Self::Sanitize(san) => Predicate::Sanitize(*san),
+            Self::FmtDebug(fd) => Predicate::FmtDebug(*fd),
+            Self::RelocationModel(rm) => Predicate::RelocationModel(*rm),
+            Self::Feature(feat) => Predicate::Feature(feat),
+            Self::TargetFeature(feat) => Predicate::TargetFeature(feat),
+            Self::Flag(flag) => Predicate::Flag(flag),
+            Self::KeyValue { key, val } => Predicate::KeyValue { key, val },
+            // The span is that of the version in the canonical rendering,
+            // ie. `version("<version>")`
+            Self::Version(version) => Predicate::Version {
+                version: *version,
+                span: 9..9 + version.to_string().len(),
+            },
+        }
+    }
+}
+
+impl From<Predicate<'_>> for OwnedPredicate {
+    fn from(pred: Predicate<'_>) -> Self {
+        match pred {
+            Predicate::Target(tp) => Self::Target(tp),
+            Predicate::Test => Self::Test,
+            Predicate::DebugAssertions => Self::DebugAssertions,
+            Predicate::ProcMacro => Self::ProcMacro,
+            Predicate::OverflowChecks => Self::OverflowChecks,
+            Predicate::UbChecks => Self::UbChecks,
+            Predicate::Doctest => Self::Doctest,
+            Predicate::Miri => Self::Miri,
+            Predicate::ContractChecks => Self::ContractChecks,
+            Predicate::Sanitize(san) => Self::Sanitize(san),
+            Predicate::FmtDebug(fd) => Self::FmtDebug(fd),
+            Predicate::RelocationModel(rm) => Self::RelocationModel(rm),
+            Predicate::Feature(feat) => Self::Feature(feat.to_owned()),
+            Predicate::TargetFeature(feat) => Self::TargetFeature(feat.to_owned()),
+            Predicate::Flag(flag) => Self::Flag(flag.to_owned()),
+            Predicate::KeyValue { key, val } => Self::KeyValue {
+                key: key.to_owned(),
+                val: val.to_owned(),
+            },
+            Predicate::Version { version, .. } => Self::Version(version),
+        }
+    }
+}
+
+impl std::fmt::Display for OwnedPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_predicate().fmt(f)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -481,19 +1089,66 @@ pub(crate) enum InnerPredicate {
     Test,
     DebugAssertions,
     ProcMacro,
+    OverflowChecks,
+    UbChecks,
+    Doctest,
+    Miri,
+    ContractChecks,
+    Sanitize(Sanitizer),
+    FmtDebug(FmtDebug),
+    RelocationModel(RelocationModel),
     Feature(Range<usize>),
     TargetFeature(Range<usize>),
     Other {
         identifier: Range<usize>,
         value: Option<Range<usize>>,
     },
+    Version {
+        version: RustcVersion,
+        span: Range<usize>,
+    },
 }
 
 impl InnerPredicate {
+    /// Moves all of the spans in the predicate by `offset`, used when the
+    /// predicate is embedded in a larger expression
+    fn shift(&mut self, offset: usize) {
+        let shift = |rng: &mut Range<usize>| *rng = rng.start + offset..rng.end + offset;
+
+        match self {
+            Self::Target(it) => {
+                if let Some(span) = &mut it.span {
+                    shift(span);
+                }
+            }
+            Self::Test
+            | Self::DebugAssertions
+            | Self::ProcMacro
+            | Self::OverflowChecks
+            | Self::UbChecks
+            | Self::Doctest
+            | Self::Miri
+            | Self::ContractChecks
+            | Self::Sanitize(_)
+            | Self::FmtDebug(_)
+            | Self::RelocationModel(_) => {}
+            Self::Feature(rng) | Self::TargetFeature(rng) => shift(rng),
+            Self::Version { span, .. } => shift(span),
+            Self::Other { identifier, value } => {
+                shift(identifier);
+                if let Some(value) = value {
+                    shift(value);
+                }
+            }
+        }
+    }
+
     fn to_pred<'a>(&self, s: &'a str) -> Predicate<'a> {
         use InnerPredicate as IP;
         use Predicate::{
-            DebugAssertions, Feature, Flag, KeyValue, ProcMacro, Target, TargetFeature, Test,
+            ContractChecks, DebugAssertions, Doctest, Feature, Flag, FmtDebug, KeyValue, Miri,
+            OverflowChecks, ProcMacro, RelocationModel, Sanitize, Target, TargetFeature, Test,
+            UbChecks, Version,
         };
 
         match self {
@@ -522,10 +1177,29 @@ impl InnerPredicate {
                     s[it.span.clone().unwrap()].to_owned(),
                 ))),
                 Which::PointerWidth(pw) => Target(TargetPredicate::PointerWidth(*pw)),
+                Which::HasAtomicLoadStore(has_atomic) => {
+                    Target(TargetPredicate::HasAtomicLoadStore(*has_atomic))
+                }
+                Which::HasAtomicEqualAlignment(has_atomic) => {
+                    Target(TargetPredicate::HasAtomicEqualAlignment(*has_atomic))
+                }
+                Which::ThreadLocal => Target(TargetPredicate::ThreadLocal),
+                Which::HasReliableF16 => Target(TargetPredicate::HasReliableF16),
+                Which::HasReliableF16Math => Target(TargetPredicate::HasReliableF16Math),
+                Which::HasReliableF128 => Target(TargetPredicate::HasReliableF128),
+                Which::HasReliableF128Math => Target(TargetPredicate::HasReliableF128Math),
             },
             IP::Test => Test,
             IP::DebugAssertions => DebugAssertions,
             IP::ProcMacro => ProcMacro,
+            IP::OverflowChecks => OverflowChecks,
+            IP::UbChecks => UbChecks,
+            IP::Doctest => Doctest,
+            IP::Miri => Miri,
+            IP::ContractChecks => ContractChecks,
+            IP::Sanitize(san) => Sanitize(*san),
+            IP::FmtDebug(fd) => FmtDebug(*fd),
+            IP::RelocationModel(rm) => RelocationModel(*rm),
             IP::Feature(rng) => Feature(&s[rng.clone()]),
             IP::TargetFeature(rng) => TargetFeature(&s[rng.clone()]),
             IP::Other { identifier, value } => match value {
@@ -535,6 +1209,31 @@ impl InnerPredicate {
                 },
                 None => Flag(&s[identifier.clone()]),
             },
+            IP::Version { version, span } => Version {
+                version: *version,
+                span: span.clone(),
+            },
+        }
+    }
+}
+
+/// Where a predicate is in the original string, see [`SpannedPredicate`]
+#[derive(Clone, Debug)]
+pub(crate) struct PredicateSpans {
+    pub(crate) span: Range<usize>,
+    pub(crate) key: Range<usize>,
+    pub(crate) value: Option<Range<usize>>,
+}
+
+impl PredicateSpans {
+    /// Moves all of the spans by `offset`, see [`InnerPredicate::shift`]
+    fn shift(&mut self, offset: usize) {
+        let shift = |rng: &mut Range<usize>| *rng = rng.start + offset..rng.end + offset;
+
+        shift(&mut self.span);
+        shift(&mut self.key);
+        if let Some(value) = &mut self.value {
+            shift(value);
         }
     }
 }
@@ -542,7 +1241,9 @@ impl InnerPredicate {
 #[derive(Clone, Debug)]
 pub(crate) enum ExprNode {
     Fn(Func),
-    Predicate(InnerPredicate),
+    Predicate(InnerPredicate, PredicateSpans),
+    /// A `true` or `false` literal
+    Literal(bool),
 }
 
 /// A parsed `cfg()` expression that can evaluated
@@ -556,16 +1257,95 @@ pub struct Expression {
 
 impl Expression {
     /// An iterator over each predicate in the expression
+    ///
+    /// The `true` and `false` literals are not predicates, and are skipped.
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::parse("any(true, unix, false)").unwrap();
+    /// assert_eq!(expr.predicates().count(), 1);
+    /// ```
     pub fn predicates(&self) -> impl Iterator<Item = Predicate<'_>> {
         self.expr.iter().filter_map(move |item| match item {
-            ExprNode::Predicate(pred) => {
+            ExprNode::Predicate(pred, _) => {
                 let pred = pred.clone().to_pred(&self.original);
                 Some(pred)
             }
-            ExprNode::Fn(_) => None,
+            ExprNode::Fn(_) | ExprNode::Literal(_) => None,
         })
     }
 
+    /// An iterator over each predicate in the expression, as an
+    /// [`OwnedPredicate`] that doesn't borrow from the expression
+    pub fn owned_predicates(&self) -> impl Iterator<Item = OwnedPredicate> + '_ {
+        self.predicates().map(OwnedPredicate::from)
+    }
+
+    /// An iterator over each predicate in the expression, in the same order
+    /// as [`Self::predicates`], along with where it is in [`Self::original`]
+    /// and the functions it is nested in
+    ///
+    /// ```
+    /// use cfg_expr::{expr::Func, Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"any(unix, not(target_os = "linux"))"#).unwrap();
+    /// let linux = expr.predicates_with_spans().nth(1).unwrap();
+    ///
+    /// assert_eq!(&expr.original()[linux.span], r#"target_os = "linux""#);
+    /// assert_eq!(&expr.original()[linux.key], "target_os");
+    /// assert_eq!(&expr.original()[linux.value.unwrap()], "linux");
+    ///
+    /// let path: Vec<_> = linux
+    ///     .path
+    ///     .iter()
+    ///     .map(|func| match func {
+    ///         Func::All(_) => "inside all()",
+    ///         Func::Any(_) => "inside any()",
+    ///         Func::Not => "inside not()",
+    ///     })
+    ///     .collect();
+    /// assert_eq!(path.join(" "), "inside not() inside any()");
+    /// ```
+    pub fn predicates_with_spans(&self) -> impl Iterator<Item = SpannedPredicate<'_>> {
+        // The expression is postfix, so walking it backwards visits each
+        // function before its arguments, and we can keep track of the
+        // functions that are currently open and how many arguments each of
+        // them has left
+        let mut open = Vec::<(Func, usize)>::new();
+        let mut predicates = Vec::new();
+
+        for node in self.expr.iter().rev() {
+            while let Some((_, 0)) = open.last() {
+                open.pop();
+            }
+
+            if let Some((_, remaining)) = open.last_mut() {
+                *remaining -= 1;
+            }
+
+            match node {
+                ExprNode::Fn(func) => open.push((
+                    *func,
+                    match func {
+                        Func::All(count) | Func::Any(count) => *count,
+                        Func::Not => 1,
+                    },
+                )),
+                ExprNode::Predicate(pred, spans) => predicates.push(SpannedPredicate {
+                    predicate: pred.to_pred(&self.original),
+                    span: spans.span.clone(),
+                    key: spans.key.clone(),
+                    value: spans.value.clone(),
+                    path: open.iter().rev().map(|(func, _)| *func).collect(),
+                }),
+                ExprNode::Literal(_) => {}
+            }
+        }
+
+        predicates.into_iter().rev()
+    }
+
     /// Evaluates the expression, using the provided closure to determine the value of
     /// each predicate, which are then combined into a final result depending on the
     /// functions `not()`, `all()`, or `any()` in the expression.
@@ -573,6 +1353,9 @@ impl Expression {
     /// `eval_predicate` typically returns `bool`, but may return any type that implements
     /// the `Logic` trait.
     ///
+    /// The `true` and `false` literals evaluate to [`Logic::top`] and [`Logic::bottom`]
+    /// respectively, without calling `eval_predicate`.
+    ///
     /// ## Examples
     ///
     /// ```
@@ -630,11 +1413,13 @@ impl Expression {
         // results according to each operator as it comes
         for node in self.expr.iter() {
             match node {
-                ExprNode::Predicate(pred) => {
+                ExprNode::Predicate(pred, _) => {
                     let pred = pred.to_pred(&self.original);
 
                     result_stack.push(eval_predicate(&pred));
                 }
+                ExprNode::Literal(true) => result_stack.push(T::top()),
+                ExprNode::Literal(false) => result_stack.push(T::bottom()),
                 ExprNode::Fn(Func::All(count)) => {
                     // all() with a comma separated list of configuration predicates.
                     let mut result = T::top();
@@ -688,7 +1473,7 @@ impl Expression {
 
 /// [`PartialEq`] will do a **syntactical** comparison, so will just check if both
 /// expressions have been parsed from the same string, **not** if they are semantically
-/// equivalent.
+/// equivalent. Use [`Expression::is_equivalent`] for a semantic comparison.
 ///
 /// ```
 /// use cfg_expr::Expression;
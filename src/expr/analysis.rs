@@ -0,0 +1,176 @@
+use crate::{
+    expr::{Expression, Func, OwnedPredicate, Predicate, TargetPredicate, sat::Formula},
+    targets::{ALL_BUILTINS, TargetInfo},
+};
+use std::ops::Range;
+
+/// A builtin target for which an [`Expression`] can be true
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BuiltinMatch {
+    /// The builtin target
+    pub target: &'static TargetInfo,
+    /// The non-target predicates, eg. `feature` or `target_feature`, that
+    /// determine if the expression is true for this target. If empty, the
+    /// expression is always true for the target.
+    pub depends_on: Vec<OwnedPredicate>,
+}
+
+/// The way in which an expression has the same result for every builtin target
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Triviality {
+    /// The expression is false for every builtin target
+    NeverTrue,
+    /// The expression is true for every builtin target
+    AlwaysTrue,
+}
+
+/// An expression that has the same result for every builtin target, see
+/// [`Expression::triviality`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trivial {
+    /// Whether the expression is never or always true
+    pub triviality: Triviality,
+    /// The spans in [`Expression::original`] of the predicates that cause the
+    /// expression to be trivial. If the expression is trivial regardless of
+    /// the target, eg. `any(feature = "a", not(feature = "a"))`, this is the
+    /// span of the entire expression.
+    pub spans: Vec<Range<usize>>,
+}
+
+impl Expression {
+    /// Retrieves each of the builtin targets in [`ALL_BUILTINS`] for which
+    /// this expression can be true
+    ///
+    /// Each [`Predicate::Target`] is matched against the target, while every
+    /// other predicate is free to be either true or false. The predicates that
+    /// can change the result for a particular target are reported in
+    /// [`BuiltinMatch::depends_on`]. Finding them is a satisfiability check
+    /// per predicate and target, which is exponential in the number of
+    /// unique non-target predicates in the worst case, see
+    /// [`Self::implies`].
+    ///
+    /// ```
+    /// use cfg_expr::{expr::OwnedPredicate, Expression};
+    ///
+    /// let expr = Expression::parse(r#"all(target_os = "linux", any(target_env = "musl", feature = "gnu"))"#).unwrap();
+    ///
+    /// let matches: Vec<_> = expr.matching_builtins().collect();
+    ///
+    /// let musl = matches.iter().find(|m| m.target.triple.as_str() == "x86_64-unknown-linux-musl").unwrap();
+    /// assert!(musl.depends_on.is_empty());
+    ///
+    /// let gnu = matches.iter().find(|m| m.target.triple.as_str() == "x86_64-unknown-linux-gnu").unwrap();
+    /// assert_eq!(gnu.depends_on, [OwnedPredicate::Feature("gnu".to_owned())]);
+    ///
+    /// assert!(matches.iter().all(|m| m.target.os == Some(cfg_expr::targets::Os::linux)));
+    /// ```
+    pub fn matching_builtins(&self) -> impl Iterator<Item = BuiltinMatch> + '_ {
+        ALL_BUILTINS.iter().filter_map(move |target| {
+            let mut formula = Formula::default();
+            formula.push_expr_with(self, |pred| match pred {
+                Predicate::Target(tp) => Some(tp.matches(target)),
+                _ => None,
+            });
+
+            if !formula.is_satisfiable() {
+                return None;
+            }
+
+            let depends_on = (0..formula.vars.len())
+                .filter(|var| formula.depends_on(*var))
+                .map(|var| formula.vars[var].clone())
+                .collect();
+
+            Some(BuiltinMatch { target, depends_on })
+        })
+    }
+
+    /// Determines if this expression is never true, or always true, for every
+    /// builtin target in [`ALL_BUILTINS`]
+    ///
+    /// This uses the values that actually occur in the builtin targets, so eg.
+    /// `target_pointer_width = "48"` is never true, as no builtin target has a
+    /// 48-bit pointer width. As with [`Self::matching_builtins`], non-target
+    /// predicates are free to be either true or false.
+    ///
+    /// The returned spans point to a minimal set of target predicates that
+    /// cause the expression to be trivial. Like [`Self::matching_builtins`],
+    /// this is exponential in the number of unique non-target predicates in
+    /// the worst case.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::Triviality, Expression};
+    ///
+    /// let expr = Expression::parse(r#"all(unix, target_os = "linux", target_os = "windows")"#).unwrap();
+    /// let trivial = expr.triviality().unwrap();
+    ///
+    /// assert_eq!(trivial.triviality, Triviality::NeverTrue);
+    /// assert_eq!(
+    ///     trivial.spans.iter().map(|s| &expr.original()[s.clone()]).collect::<Vec<_>>(),
+    ///     [r#"target_os = "linux""#, r#"target_os = "windows""#],
+    /// );
+    ///
+    /// assert!(Expression::parse("unix").unwrap().triviality().is_none());
+    /// ```
+    pub fn triviality(&self) -> Option<Trivial> {
+        let mut targets = Vec::<TargetPredicate>::new();
+        for pred in self.predicates() {
+            if let Predicate::Target(tp) = pred {
+                if !targets.contains(&tp) {
+                    targets.push(tp);
+                }
+            }
+        }
+
+        // Checks if the expression is trivial when only the target predicates
+        // marked as fixed are matched against each target
+        let holds = |fixed: &[bool], triviality: Triviality| -> bool {
+            ALL_BUILTINS.iter().all(|target| {
+                let mut formula = Formula::default();
+                formula.push_expr_with(self, |pred| match pred {
+                    Predicate::Target(tp) => {
+                        let ind = targets.iter().position(|t| t == tp)?;
+                        fixed[ind].then(|| tp.matches(target))
+                    }
+                    _ => None,
+                });
+
+                if triviality == Triviality::AlwaysTrue {
+                    formula.push_fn(Func::Not);
+                }
+
+                !formula.is_satisfiable()
+            })
+        };
+
+        let mut fixed = vec![true; targets.len()];
+        let triviality = [Triviality::NeverTrue, Triviality::AlwaysTrue]
+            .into_iter()
+            .find(|triviality| holds(&fixed, *triviality))?;
+
+        // Free each target predicate in turn, keeping only the ones that are
+        // needed for the expression to remain trivial
+        for ind in 0..targets.len() {
+            fixed[ind] = false;
+            if !holds(&fixed, triviality) {
+                fixed[ind] = true;
+            }
+        }
+
+        let spans = if fixed.contains(&true) {
+            self.predicates_with_spans()
+                .filter_map(|sp| match sp.predicate {
+                    Predicate::Target(tp) => {
+                        let ind = targets.iter().position(|t| *t == tp)?;
+                        fixed[ind].then_some(sp.span)
+                    }
+                    _ => None,
+                })
+                .collect()
+        } else {
+            std::iter::once(0..self.original.len()).collect()
+        };
+
+        Some(Trivial { triviality, spans })
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::{Dialect, ExprNode, Expression, Func, ParseOptions, Predicate},
+};
+use smallvec::SmallVec;
+
+impl Expression {
+    /// Creates an `all()` of each of the specified expressions
+    ///
+    /// The resulting [`Expression::original`] is the canonical rendering of
+    /// the expression, so it can be parsed back into an equivalent [`Expression`]
+    ///
+    /// ```
+    /// use cfg_expr::{expr::TargetPredicate, targets::Os, Expression, Predicate};
+    ///
+    /// let expr = Expression::all([
+    ///     Predicate::Target(TargetPredicate::Os(Os::linux)).try_into().unwrap(),
+    ///     Expression::parse("feature   =   \"cool\"").unwrap(),
+    /// ]);
+    ///
+    /// assert_eq!(expr.original(), r#"all(target_os = "linux", feature = "cool")"#);
+    /// ```
+    pub fn all(exprs: impl IntoIterator<Item = Expression>) -> Self {
+        Self::join(Func::All(0), exprs.into_iter().map(|e| e.canonical()))
+    }
+
+    /// Creates an `any()` of each of the specified expressions
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::any([
+    ///     Predicate::Test.try_into().unwrap(),
+    ///     Predicate::Miri.try_into().unwrap(),
+    /// ]);
+    ///
+    /// assert_eq!(expr.original(), "any(test, miri)");
+    /// ```
+    pub fn any(exprs: impl IntoIterator<Item = Expression>) -> Self {
+        Self::join(Func::Any(0), exprs.into_iter().map(|e| e.canonical()))
+    }
+
+    /// Creates a `not()` of the specified expression
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::not(Expression::parse("unix").unwrap());
+    ///
+    /// assert_eq!(expr.original(), "not(unix)");
+    /// ```
+    // This is a constructor rather than an operator on an existing expression
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(expr: Expression) -> Self {
+        Self::join(Func::Not, std::iter::once(expr.canonical()))
+    }
+
+    /// Renders the expression in its canonical form
+    fn canonical(&self) -> Self {
+        let mut stack = SmallVec::<[Expression; 5]>::new();
+
+        for node in &self.expr {
+            match node {
+                ExprNode::Predicate(pred, _) => {
+                    stack.push(
+                        Self::try_from(pred.to_pred(&self.original))
+                            .expect("parsed predicates can be parsed again"),
+                    );
+                }
+                ExprNode::Literal(value) => stack.push(Self::literal(*value)),
+                ExprNode::Fn(func) => {
+                    let count = match func {
+                        Func::All(count) | Func::Any(count) => *count,
+                        Func::Not => 1,
+                    };
+
+                    let operands = stack
+                        .drain(stack.len() - count..)
+                        .collect::<SmallVec<[_; 5]>>();
+                    stack.push(Self::join(*func, operands));
+                }
+            }
+        }
+
+        stack.pop().unwrap()
+    }
+
+    /// Creates a `true` or `false` literal
+    pub(super) fn literal(value: bool) -> Self {
+        let mut expr = SmallVec::new();
+        expr.push(ExprNode::Literal(value));
+
+        Self {
+            expr,
+            original: if value { "true" } else { "false" }.to_owned(),
+        }
+    }
+
+    /// Combines canonical expressions into a single function call, moving each
+    /// operand's spans to their new location
+    pub(super) fn join(func: Func, operands: impl IntoIterator<Item = Expression>) -> Self {
+        let mut original = String::from(match func {
+            Func::All(_) => "all(",
+            Func::Any(_) => "any(",
+            Func::Not => "not(",
+        });
+        let mut expr = SmallVec::new();
+        let mut count = 0;
+
+        for operand in operands {
+            if count > 0 {
+                original.push_str(", ");
+            }
+
+            let offset = original.len();
+            original.push_str(&operand.original);
+
+            expr.extend(operand.expr.into_iter().map(|mut node| {
+                if let ExprNode::Predicate(pred, spans) = &mut node {
+                    pred.shift(offset);
+                    spans.shift(offset);
+                }
+                node
+            }));
+
+            count += 1;
+        }
+
+        original.push(')');
+        expr.push(ExprNode::Fn(match func {
+            Func::All(_) => Func::All(count),
+            Func::Any(_) => Func::Any(count),
+            Func::Not => Func::Not,
+        }));
+
+        Self { expr, original }
+    }
+}
+
+impl TryFrom<Predicate<'_>> for Expression {
+    type Error = ParseError;
+
+    /// Parses the rendering of the predicate, so the expression is the same
+    /// as if the predicate had been parsed, eg. `Flag("unix")` is a target
+    /// family. Rustc's rules are used as they accept any name, with or
+    /// without a value.
+    ///
+    /// Fails if the predicate can't be rendered as a single predicate, eg. a
+    /// [`Predicate::Flag`] that is empty or isn't an identifier
+    fn try_from(pred: Predicate<'_>) -> Result<Self, Self::Error> {
+        let original = pred.to_string();
+        let expr = Self::parse_with_options(
+            &original,
+            ParseOptions {
+                dialect: Dialect::Rustc,
+                ..Default::default()
+            },
+        )?;
+
+        // A name that isn't an identifier can still render as a valid
+        // predicate, eg. `Flag("a = \"b\"")` renders as `a = "b"`
+        let matches = match (&pred, expr.expr.as_slice()) {
+            (
+                Predicate::Flag(name) | Predicate::KeyValue { key: name, .. },
+                [ExprNode::Predicate(_, spans)],
+            ) => {
+                let key = &original[spans.key.clone()];
+                key.strip_prefix("r#").unwrap_or(key) == *name
+                    && spans.value.is_some() == matches!(pred, Predicate::KeyValue { .. })
+            }
+            (_, [ExprNode::Predicate(..)]) => true,
+            _ => false,
+        };
+
+        if matches {
+            Ok(expr)
+        } else {
+            Err(ParseError {
+                span: 0..original.len(),
+                original,
+                reason: Reason::InvalidCharacters,
+            })
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use crate::{
+    error::{Diagnostic, ParseError, Reason, closest},
+    expr::{
+        Expression, FmtDebug, Predicate, RelocationModel, Sanitizer,
+        lexer::{Lexer, LexerToken, Token},
+    },
+    targets::TargetInfo,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Range,
+};
+
+/// The values that are expected for a cfg name
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExpectedValues {
+    /// The name may be used without a value, ie. `none()`
+    pub none: bool,
+    /// Any value is expected, ie. `any()`
+    pub any: bool,
+    /// The expected values
+    pub values: BTreeSet<String>,
+}
+
+impl ExpectedValues {
+    fn merge(&mut self, other: &Self) {
+        self.none |= other.none;
+        self.any |= other.any;
+        self.values.extend(other.values.iter().cloned());
+    }
+
+    fn expects(&self, value: Option<&str>) -> bool {
+        self.any
+            || match value {
+                Some(value) => self.values.contains(value),
+                None => self.none,
+            }
+    }
+}
+
+/// Whether the name or the value of a cfg was unexpected
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UnexpectedKind {
+    /// The name isn't expected at all
+    Name,
+    /// The name is expected, but not with this value, or without a value
+    Value,
+}
+
+/// A cfg in an expression that isn't expected by a [`CheckCfg`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnexpectedCfg {
+    /// The name of the cfg, without any `r#` prefix
+    pub name: String,
+    /// The value of the cfg, if it has one
+    pub value: Option<String>,
+    /// Whether the name or the value was unexpected
+    pub kind: UnexpectedKind,
+    /// The span in [`Expression::original`] of the unexpected name, or the
+    /// unexpected value without its quotes. If a value was expected but the
+    /// name was used without one, this is the span of the name.
+    pub span: Range<usize>,
+    /// The closest expected name or value, if it's close enough to likely be
+    /// a typo, eg. `macos` for `target_os = "macosx"`
+    pub suggestion: Option<String>,
+}
+
+impl UnexpectedCfg {
+    /// Creates a [`Diagnostic`] for this cfg in the expression it was found in
+    pub fn diagnostic(&self, expr: &Expression) -> Diagnostic {
+        let message = match (self.kind, &self.value) {
+            (UnexpectedKind::Name, _) => format!("unexpected cfg name `{}`", self.name),
+            (UnexpectedKind::Value, Some(value)) => {
+                format!("unexpected value `\"{value}\"` for `{}`", self.name)
+            }
+            (UnexpectedKind::Value, None) => format!("`{}` expects a value", self.name),
+        };
+
+        Diagnostic::new(
+            expr.original(),
+            message,
+            self.span.clone(),
+            self.suggestion.as_deref(),
+        )
+    }
+}
+
+/// The cfg names and values that are expected, as declared with rustc's
+/// [`--check-cfg`](https://doc.rust-lang.org/nightly/rustc/check-cfg.html)
+///
+/// This mirrors the `unexpected_cfgs` lint, so that typos in expressions can
+/// be caught without running the compiler.
+///
+/// ```
+/// use cfg_expr::{expr::{CheckCfg, UnexpectedKind}, targets::ALL_BUILTINS, Expression};
+///
+/// let mut check = CheckCfg::well_known(ALL_BUILTINS);
+/// // Cargo declares these for every crate, along with its features
+/// check.add_spec("cfg(docsrs, test)").unwrap();
+/// check.add_spec(r#"cfg(feature, values("std", "alloc"))"#).unwrap();
+///
+/// let expr = Expression::parse(r#"all(target_os = "linx", feature = "std", tset)"#).unwrap();
+/// let unexpected = check.check(&expr);
+///
+/// assert_eq!(unexpected.len(), 2);
+/// assert_eq!(unexpected[0].kind, UnexpectedKind::Value);
+/// assert_eq!(&expr.original()[unexpected[0].span.clone()], "linx");
+/// assert_eq!(unexpected[1].kind, UnexpectedKind::Name);
+/// assert_eq!(&expr.original()[unexpected[1].span.clone()], "tset");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CheckCfg {
+    /// Any name is expected, ie. `cfg(any())`
+    pub any_name: bool,
+    /// The expected values of each expected name
+    pub names: BTreeMap<String, ExpectedValues>,
+}
+
+impl CheckCfg {
+    /// Creates the well-known names and values that rustc expects, with the
+    /// values of the target cfgs taken from the specified targets, eg.
+    /// [`ALL_BUILTINS`](crate::targets::ALL_BUILTINS)
+    ///
+    /// The builtin targets only record some of the target features, so every
+    /// `target_feature` value is expected. Note that unlike Cargo, rustc
+    /// doesn't expect `test`, `docsrs`, or any `feature` values by default.
+    pub fn well_known<'a>(targets: impl IntoIterator<Item = &'a TargetInfo>) -> Self {
+        const NAMES: &[&str] = &[
+            "clippy",
+            "contract_checks",
+            "debug_assertions",
+            "doc",
+            "doctest",
+            "miri",
+            "overflow_checks",
+            "proc_macro",
+            "rustfmt",
+            "sanitizer_cfi_generalize_pointers",
+            "sanitizer_cfi_normalize_integers",
+            "target_has_reliable_f128",
+            "target_has_reliable_f128_math",
+            "target_has_reliable_f16",
+            "target_has_reliable_f16_math",
+            "target_thread_local",
+            "ub_checks",
+            "unix",
+            "windows",
+        ];
+        const ATOMICS: &[&str] = &["8", "16", "32", "64", "128", "ptr"];
+
+        let mut check = Self::default();
+
+        for name in NAMES {
+            check.expect(name, []).none = true;
+        }
+
+        check.expect("target_feature", []).any = true;
+        check
+            .expect("target_has_atomic", ATOMICS.iter().copied())
+            .none = true;
+        check
+            .expect("target_has_atomic_load_store", ATOMICS.iter().copied())
+            .none = true;
+        check.expect("target_has_atomic_equal_alignment", ATOMICS.iter().copied());
+        check.expect(
+            "target_has_atomic_primitive_alignment",
+            ATOMICS.iter().copied(),
+        );
+        check.expect("target_endian", ["big", "little"]);
+        check.expect("panic", ["abort", "immediate-abort", "unwind"]);
+        check.expect("fmt_debug", FmtDebug::ALL.iter().map(|v| v.as_str()));
+        check.expect(
+            "relocation_model",
+            RelocationModel::ALL.iter().map(|v| v.as_str()),
+        );
+        check.expect("sanitize", Sanitizer::ALL.iter().map(|v| v.as_str()));
+
+        for target in targets {
+            check.expect("target_family", target.families.iter().map(|f| f.as_str()));
+
+            let pointer_width = target.pointer_width.to_string();
+            for (name, value) in [
+                ("target_arch", target.arch.as_str()),
+                (
+                    "target_os",
+                    target.os.as_ref().map_or("none", |os| os.as_str()),
+                ),
+                (
+                    "target_env",
+                    target.env.as_ref().map_or("", |env| env.as_str()),
+                ),
+                (
+                    "target_abi",
+                    target.abi.as_ref().map_or("", |abi| abi.as_str()),
+                ),
+                (
+                    "target_vendor",
+                    target.vendor.as_ref().map_or("unknown", |v| v.as_str()),
+                ),
+                ("target_pointer_width", &pointer_width),
+                ("panic", target.panic.as_str()),
+            ] {
+                check.expect(name, [value]);
+            }
+        }
+
+        check
+    }
+
+    /// Adds the names and values declared by a single `--check-cfg` spec, eg.
+    /// `cfg(name, values("a", "b"))`
+    ///
+    /// A name without `values()` is expected without a value, the same as
+    /// `values(none())`, while `values(any())` expects any value. `cfg(any())`
+    /// expects any name, and `cfg()` declares nothing. Declaring the same name
+    /// more than once expects the union of the values.
+    pub fn add_spec(&mut self, spec: &str) -> Result<(), ParseError> {
+        let mut tokens = SpecTokens::new(spec)?;
+
+        let mut names = Vec::new();
+        let mut any_name = false;
+        let mut values = None;
+
+        while let Some(lt) = tokens.next() {
+            // `values()` must be the last argument
+            if values.is_some() {
+                return Err(tokens.err(lt.span, Reason::Unexpected(&[")"])));
+            }
+
+            match lt.token {
+                Token::Key("values") => {
+                    tokens.expect(Token::OpenParen, &["("])?;
+                    values = Some(tokens.values()?);
+                }
+                Token::Key(name) => names.push(name.strip_prefix("r#").unwrap_or(name)),
+                Token::Any => {
+                    tokens.expect(Token::OpenParen, &["("])?;
+                    tokens.expect(Token::CloseParen, &[")"])?;
+                    any_name = true;
+                }
+                _ => {
+                    return Err(
+                        tokens.err(lt.span, Reason::Unexpected(&["<key>", "any", "values"]))
+                    );
+                }
+            }
+
+            match tokens.next() {
+                None => break,
+                Some(lt) if lt.token == Token::Comma => {}
+                Some(lt) => return Err(tokens.err(lt.span, Reason::Unexpected(&[",", ")"]))),
+            }
+        }
+
+        let values = values.unwrap_or(ExpectedValues {
+            none: true,
+            ..Default::default()
+        });
+
+        self.any_name |= any_name;
+        for name in names {
+            self.expect(name, []).merge(&values);
+        }
+
+        Ok(())
+    }
+
+    /// Expects the name, with the specified values
+    fn expect<'v>(
+        &mut self,
+        name: &str,
+        values: impl IntoIterator<Item = &'v str>,
+    ) -> &mut ExpectedValues {
+        let expected = self.names.entry(name.to_owned()).or_default();
+        expected
+            .values
+            .extend(values.into_iter().map(str::to_owned));
+        expected
+    }
+
+    /// Merges the names and values expected by another `CheckCfg` into this
+    /// one
+    pub fn merge(&mut self, other: &Self) {
+        self.any_name |= other.any_name;
+        for (name, values) in &other.names {
+            self.expect(name, []).merge(values);
+        }
+    }
+
+    /// Checks every predicate in the expression, returning each unexpected
+    /// name or value, in source order
+    pub fn check(&self, expr: &Expression) -> Vec<UnexpectedCfg> {
+        let mut unexpected = Vec::new();
+
+        for sp in expr.predicates_with_spans() {
+            // `version()` isn't a cfg
+            if let Predicate::Version { .. } = sp.predicate {
+                continue;
+            }
+
+            let key = &expr.original()[sp.key.clone()];
+            let value = sp.value.map(|span| (&expr.original()[span.clone()], span));
+
+            let name = key.strip_prefix("r#").unwrap_or(key);
+            let (kind, span, suggestion) = match self.names.get(name) {
+                Some(expected) if expected.expects(value.as_ref().map(|(v, _)| *v)) => continue,
+                Some(expected) => match &value {
+                    Some((value, span)) => (
+                        UnexpectedKind::Value,
+                        span.clone(),
+                        closest(value, expected.values.iter().map(String::as_str)),
+                    ),
+                    None => (UnexpectedKind::Value, sp.key, None),
+                },
+                None if self.any_name => continue,
+                None => (
+                    UnexpectedKind::Name,
+                    sp.key,
+                    closest(name, self.names.keys().map(String::as_str)),
+                ),
+            };
+
+            unexpected.push(UnexpectedCfg {
+                name: name.to_owned(),
+                value: value.map(|(v, _)| v.to_owned()),
+                kind,
+                span,
+                suggestion: suggestion.map(str::to_owned),
+            });
+        }
+
+        unexpected
+    }
+}
+
+/// The tokens of a `--check-cfg` spec, with spans relative to the full spec
+struct SpecTokens<'a> {
+    spec: &'a str,
+    tokens: std::vec::IntoIter<LexerToken<'a>>,
+}
+
+impl<'a> SpecTokens<'a> {
+    fn new(spec: &'a str) -> Result<Self, ParseError> {
+        let err = |span: Range<usize>, reason: Reason| ParseError {
+            original: spec.to_owned(),
+            span,
+            reason,
+        };
+
+        let Some(inner) = spec.strip_prefix("cfg(") else {
+            return Err(err(0..spec.len(), Reason::Unexpected(&["cfg("])));
+        };
+        let Some(inner) = inner.strip_suffix(')') else {
+            return Err(err(3..spec.len(), Reason::UnclosedParens));
+        };
+
+        let shift = |span: Range<usize>| span.start + 4..span.end + 4;
+        let tokens = Lexer::new(inner)
+            .map(|lt| match lt {
+                Ok(lt) => Ok(LexerToken {
+                    token: lt.token,
+                    span: shift(lt.span),
+                }),
+                Err(e) => Err(err(shift(e.span), e.reason)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            spec,
+            tokens: tokens.into_iter(),
+        })
+    }
+
+    fn err(&self, span: Range<usize>, reason: Reason) -> ParseError {
+        ParseError {
+            original: self.spec.to_owned(),
+            span,
+            reason,
+        }
+    }
+
+    fn next(&mut self) -> Option<LexerToken<'a>> {
+        self.tokens.next()
+    }
+
+    /// Retrieves the next token, which must exist since the spec is still
+    /// inside a parens
+    fn require(&mut self) -> Result<LexerToken<'a>, ParseError> {
+        let end = self.spec.len() - 1;
+        self.next()
+            .ok_or_else(|| self.err(end..end, Reason::UnclosedParens))
+    }
+
+    fn expect(
+        &mut self,
+        expected: Token<'_>,
+        what: &'static [&'static str],
+    ) -> Result<(), ParseError> {
+        let lt = self.require()?;
+        if lt.token == expected {
+            Ok(())
+        } else {
+            Err(self.err(lt.span, Reason::Unexpected(what)))
+        }
+    }
+
+    /// Parses the contents of a `values()`, after its opening parens
+    fn values(&mut self) -> Result<ExpectedValues, ParseError> {
+        let mut values = ExpectedValues::default();
+
+        loop {
+            let lt = self.require()?;
+            match lt.token {
+                Token::CloseParen => break,
+                Token::Value(value) => {
+                    values.values.insert(value.to_owned());
+                }
+                Token::Key("none") => {
+                    self.expect(Token::OpenParen, &["("])?;
+                    self.expect(Token::CloseParen, &[")"])?;
+                    values.none = true;
+                }
+                Token::Any => {
+                    self.expect(Token::OpenParen, &["("])?;
+                    self.expect(Token::CloseParen, &[")"])?;
+                    values.any = true;
+                }
+                _ => {
+                    return Err(
+                        self.err(lt.span, Reason::Unexpected(&["\"<value>\"", "none", "any"]))
+                    );
+                }
+            }
+
+            let lt = self.require()?;
+            match lt.token {
+                Token::Comma => {}
+                Token::CloseParen => break,
+                _ => return Err(self.err(lt.span, Reason::Unexpected(&[",", ")"]))),
+            }
+        }
+
+        Ok(values)
+    }
+}
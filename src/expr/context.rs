@@ -0,0 +1,215 @@
+use crate::expr::{
+    Expression, FmtDebug, Predicate, RelocationModel, RustcVersion, Sanitizer, TargetMatcher,
+};
+
+/// The compiler settings that rustc sets cfgs for, other than the target
+///
+/// Each setting is `None` if it's unknown, in which case the predicates that
+/// depend on it evaluate to `None` as well. The [`Default`] is that every
+/// setting is unknown.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// `debug_assertions`
+    pub debug_assertions: Option<bool>,
+    /// `overflow_checks`
+    pub overflow_checks: Option<bool>,
+    /// `ub_checks`
+    pub ub_checks: Option<bool>,
+    /// `test`, ie. compiling with `--test`
+    pub test: Option<bool>,
+    /// `doctest`, ie. compiling a doctest
+    pub doctest: Option<bool>,
+    /// `proc_macro`, ie. compiling a `proc-macro` crate
+    pub proc_macro: Option<bool>,
+    /// `miri`, ie. running under Miri
+    pub miri: Option<bool>,
+    /// `contract_checks`
+    pub contract_checks: Option<bool>,
+    /// The enabled `sanitize` cfgs
+    pub sanitizers: Option<Vec<Sanitizer>>,
+    /// `fmt_debug`
+    pub fmt_debug: Option<FmtDebug>,
+    /// `relocation_model`, which depends on the target as well as the flags
+    pub relocation_model: Option<RelocationModel>,
+}
+
+impl Profile {
+    /// The settings of Cargo's `dev` profile, when not testing
+    ///
+    /// The relocation model depends on the target, so it's unknown.
+    pub fn dev() -> Self {
+        Self {
+            debug_assertions: Some(true),
+            overflow_checks: Some(true),
+            ub_checks: Some(true),
+            ..Self::release()
+        }
+    }
+
+    /// The settings of Cargo's `release` profile, when not testing
+    ///
+    /// The relocation model depends on the target, so it's unknown.
+    pub fn release() -> Self {
+        Self {
+            debug_assertions: Some(false),
+            overflow_checks: Some(false),
+            ub_checks: Some(false),
+            test: Some(false),
+            doctest: Some(false),
+            proc_macro: Some(false),
+            miri: Some(false),
+            contract_checks: Some(false),
+            sanitizers: Some(Vec::new()),
+            fmt_debug: Some(FmtDebug::Full),
+            relocation_model: None,
+        }
+    }
+}
+
+/// Everything that determines the value of the predicates in an expression
+/// for a particular compilation, see [`Expression::eval_in`]
+///
+/// ```
+/// use cfg_expr::{expr::CompilationContext, targets::get_builtin_target_by_triple, Expression};
+///
+/// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+///
+/// let mut ctx = CompilationContext::new(linux);
+/// ctx.features = Some(vec!["std".to_owned()]);
+///
+/// let expr = Expression::parse(r#"all(unix, feature = "std", debug_assertions, not(test))"#).unwrap();
+/// let enabled: bool = expr.eval_in(&ctx);
+/// assert!(enabled);
+///
+/// // Nothing is known about the `--cfg` flags, so custom flags are unknown
+/// ctx.unknown_cfgs = true;
+/// let expr = Expression::parse("any(windows, my_flag)").unwrap();
+/// let enabled: Option<bool> = expr.eval_in(&ctx);
+/// assert_eq!(enabled, None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompilationContext<T> {
+    /// The target, eg. a builtin [`TargetInfo`](crate::targets::TargetInfo),
+    /// a [`CfgSet`](crate::targets::CfgSet), or a `target_lexicon::Triple`
+    pub target: T,
+    /// The enabled `target_feature`s, or `None` if unknown
+    pub target_features: Option<Vec<String>>,
+    /// The enabled Cargo `feature`s, or `None` if unknown
+    pub features: Option<Vec<String>>,
+    /// The profile settings
+    pub profile: Profile,
+    /// The version of rustc, or `None` if unknown
+    pub rustc_version: Option<RustcVersion>,
+    /// Extra cfgs passed with `--cfg`, either a bare `name` with no value,
+    /// or a `name="value"`
+    pub cfgs: Vec<(String, Option<String>)>,
+    /// Whether the `--cfg` flags are only partially known, in which case any
+    /// other flag or key-value not in [`Self::cfgs`] is unknown rather than
+    /// false
+    pub unknown_cfgs: bool,
+}
+
+impl<T: TargetMatcher> CompilationContext<T> {
+    /// Creates a context for the target using Cargo's [`Profile::dev`], with
+    /// no target features, Cargo features, or extra cfgs, and an unknown rustc
+    /// version
+    pub fn new(target: T) -> Self {
+        Self {
+            target,
+            target_features: Some(Vec::new()),
+            features: Some(Vec::new()),
+            profile: Profile::dev(),
+            rustc_version: None,
+            cfgs: Vec::new(),
+            unknown_cfgs: false,
+        }
+    }
+
+    /// Evaluates a single predicate in this context, returning `None` if the
+    /// context marks the part it depends on as unknown
+    pub fn eval_predicate(&self, pred: &Predicate<'_>) -> Option<bool> {
+        let profile = &self.profile;
+        let contains = |set: &Option<Vec<String>>, name: &str| {
+            set.as_ref().map(|set| set.iter().any(|s| s == name))
+        };
+        let has_cfg = |key: &str, val: Option<&str>| {
+            if self
+                .cfgs
+                .iter()
+                .any(|(k, v)| k == key && v.as_deref() == val)
+            {
+                Some(true)
+            } else if self.unknown_cfgs {
+                None
+            } else {
+                Some(false)
+            }
+        };
+
+        match pred {
+            Predicate::Target(tp) => Some(self.target.matches(tp)),
+            Predicate::TargetFeature(feat) => contains(&self.target_features, feat),
+            Predicate::Feature(feat) => contains(&self.features, feat),
+            Predicate::DebugAssertions => profile.debug_assertions,
+            Predicate::OverflowChecks => profile.overflow_checks,
+            Predicate::UbChecks => profile.ub_checks,
+            Predicate::Test => profile.test,
+            Predicate::Doctest => profile.doctest,
+            Predicate::ProcMacro => profile.proc_macro,
+            Predicate::Miri => profile.miri,
+            Predicate::ContractChecks => profile.contract_checks,
+            Predicate::Sanitize(san) => profile.sanitizers.as_ref().map(|s| s.contains(san)),
+            Predicate::FmtDebug(fd) => profile.fmt_debug.map(|f| f == *fd),
+            Predicate::RelocationModel(rm) => profile.relocation_model.map(|r| r == *rm),
+            Predicate::Version { version, .. } => self
+                .rustc_version
+                .as_ref()
+                .map(|rustc| version.matches(rustc)),
+            Predicate::Flag(flag) => has_cfg(flag, None),
+            Predicate::KeyValue { key, val } => has_cfg(key, Some(val)),
+        }
+    }
+}
+
+/// The result of [`Expression::eval_in`]
+///
+/// For `Option<bool>`, the result is `None` if it depends on a part of the
+/// context that is unknown, while for `bool` such a result is `false`, the
+/// same as a cfg that isn't set.
+pub trait EvalResult {
+    /// Converts the three-valued result of the evaluation
+    fn from_result(result: Option<bool>) -> Self;
+}
+
+impl EvalResult for bool {
+    #[inline]
+    fn from_result(result: Option<bool>) -> Self {
+        result.unwrap_or(false)
+    }
+}
+
+impl EvalResult for Option<bool> {
+    #[inline]
+    fn from_result(result: Option<bool>) -> Self {
+        result
+    }
+}
+
+impl Expression {
+    /// Evaluates this expression in a compilation context, see
+    /// [`CompilationContext::eval_predicate`]
+    ///
+    /// The expression is evaluated with the three-valued logic of
+    /// `Option<bool>`, so a predicate whose value is unknown only makes the
+    /// result unknown if the result actually depends on it, eg.
+    /// `any(unix, feature = "a")` is true for a unix target even if the
+    /// features are unknown. See [`EvalResult`] for how an unknown result is
+    /// converted to a `bool`.
+    pub fn eval_in<T, R>(&self, ctx: &CompilationContext<T>) -> R
+    where
+        T: TargetMatcher,
+        R: EvalResult,
+    {
+        R::from_result(self.eval(|pred| ctx.eval_predicate(pred)))
+    }
+}
@@ -1,10 +1,14 @@
-use crate::error::{ParseError, Reason};
+use crate::{
+    error::{ParseError, Reason},
+    expr::Dialect,
+};
 
 /// A single token in a cfg expression
 /// <https://doc.rust-lang.org/reference/conditional-compilation.html>
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Token<'a> {
-    /// A single contiguous term
+    /// A single contiguous term, raw identifiers keep their `r#` prefix, eg. `r#true`
     Key(&'a str),
     /// A single contiguous value, without its surrounding quotes
     Value(&'a str),
@@ -16,6 +20,10 @@ pub enum Token<'a> {
     Any,
     /// Beginning of a `not()` predicate
     Not,
+    /// Beginning of a `version()` predicate. Only lexed when `version` is
+    /// followed by a `(`, and never in the [`Dialect::Cargo`] dialect,
+    /// otherwise it is a normal [`Token::Key`]
+    Version,
     /// A `(` for starting a predicate list
     OpenParen,
     /// A `)` for ending a predicate list
@@ -38,12 +46,14 @@ impl Token<'_> {
             Token::Value(s) => s.len() + 2,
             Token::Equals | Token::OpenParen | Token::CloseParen | Token::Comma => 1,
             Token::All | Token::Any | Token::Not => 3,
+            Token::Version => 7,
         }
     }
 }
 
 /// Allows iteration through a cfg expression, yielding
-/// a token or a `ParseError`.
+/// a token or a `ParseError`. After an error, lexing continues
+/// past the offending characters.
 ///
 /// Prefer to use `Expression::parse` rather than directly
 /// using the lexer
@@ -51,12 +61,19 @@ pub struct Lexer<'a> {
     pub(super) inner: &'a str,
     original: &'a str,
     offset: usize,
+    dialect: Dialect,
 }
 
 impl<'a> Lexer<'a> {
     /// Creates a Lexer over a cfg expression, it can either be
     /// a raw expression eg `key` or in attribute form, eg `cfg(key)`
     pub fn new(text: &'a str) -> Self {
+        Self::with_dialect(text, Dialect::default())
+    }
+
+    /// Creates a Lexer over a cfg expression that follows the lexical rules
+    /// of the dialect, eg. which characters are whitespace
+    pub fn with_dialect(text: &'a str, dialect: Dialect) -> Self {
         let text = if text.starts_with("cfg(") && text.ends_with(')') {
             &text[4..text.len() - 1]
         } else {
@@ -67,6 +84,7 @@ impl<'a> Lexer<'a> {
             inner: text,
             original: text,
             offset: 0,
+            dialect,
         }
     }
 }
@@ -86,7 +104,18 @@ impl<'a> Iterator for Lexer<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         // Jump over any whitespace, updating `self.inner` and `self.offset` appropriately
-        let non_whitespace_index = match self.inner.find(|c: char| !c.is_whitespace()) {
+        let dialect = self.dialect;
+        let is_whitespace = move |c: char| match dialect {
+            Dialect::Strict => c.is_whitespace(),
+            // rustc only treats Pattern_White_Space as whitespace
+            Dialect::Rustc => matches!(
+                c,
+                '\t'..='\r' | ' ' | '\u{85}' | '\u{200E}' | '\u{200F}' | '\u{2028}' | '\u{2029}'
+            ),
+            Dialect::Cargo => c == ' ',
+        };
+
+        let non_whitespace_index = match self.inner.find(|c: char| !is_whitespace(c)) {
             Some(idx) => idx,
             None => self.inner.len(),
         };
@@ -120,6 +149,15 @@ impl<'a> Iterator for Lexer<'a> {
                             reason: Reason::UnclosedQuotes,
                         })),
                     }
+                } else if let Some(raw) = self
+                    .inner
+                    .strip_prefix("r#")
+                    .filter(|raw| raw.starts_with(is_ident_start))
+                {
+                    // Raw identifiers are always keys, even if they are
+                    // otherwise keywords like `true` or `all`
+                    let len = raw.find(|c: char| !is_ident_rest(c)).unwrap_or(raw.len());
+                    Some(Ok(Token::Key(&self.inner[..len + 2])))
                 } else if is_ident_start(c) {
                     let substr = match self.inner[1..].find(|c: char| !is_ident_rest(c)) {
                         Some(ind) => &self.inner[..=ind],
@@ -130,33 +168,52 @@ impl<'a> Iterator for Lexer<'a> {
                         "all" => Some(Ok(Token::All)),
                         "any" => Some(Ok(Token::Any)),
                         "not" => Some(Ok(Token::Not)),
+                        "version"
+                            if dialect != Dialect::Cargo
+                                && self.inner[substr.len()..]
+                                    .trim_start_matches(is_whitespace)
+                                    .starts_with('(') =>
+                        {
+                            Some(Ok(Token::Version))
+                        }
                         other => Some(Ok(Token::Key(other))),
                     }
                 } else {
-                    // clippy tries to help here, but we need
-                    // a Range here, not a RangeInclusive<>
-                    #[allow(clippy::range_plus_one)]
                     Some(Err(ParseError {
                         original: self.original.to_owned(),
-                        span: self.offset..self.offset + 1,
+                        span: self.offset..self.offset + c.len_utf8(),
                         reason: Reason::Unexpected(&["<key>", "all", "any", "not"]),
                     }))
                 }
             }
         }
-        .map(|tok| {
-            tok.map(|tok| {
+        .map(|tok| match tok {
+            Ok(tok) => {
                 let len = tok.len();
 
                 let start = self.offset;
                 self.inner = &self.inner[len..];
                 self.offset += len;
 
-                LexerToken {
+                Ok(LexerToken {
                     token: tok,
                     span: start..self.offset,
-                }
-            })
+                })
+            }
+            Err(err) => {
+                // Skip over the problem so that lexing can continue after it,
+                // an unclosed quote swallows the rest of the string
+                let len = if err.reason == Reason::UnclosedQuotes {
+                    self.inner.len()
+                } else {
+                    self.inner.chars().next().map_or(0, char::len_utf8)
+                };
+
+                self.inner = &self.inner[len..];
+                self.offset += len;
+
+                Err(err)
+            }
         })
     }
 }
@@ -1,12 +1,63 @@
 use crate::{
     error::{ParseError, Reason},
     expr::{
-        ExprNode, Expression, Func, InnerPredicate,
+        ExprNode, Expression, Func, InnerPredicate, PredicateSpans, Which,
         lexer::{Lexer, Token},
     },
 };
 use smallvec::SmallVec;
 
+/// The set of rules that decides which expressions are valid
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Dialect {
+    /// The rules of this crate, which are stricter than rustc's. Predicates
+    /// that can never be true are rejected, eg. `feature` without a value, or
+    /// `unix` with one, as are invalid values of the builtin cfgs, eg.
+    /// `target_pointer_width = "x"`. On the other hand, any Unicode whitespace
+    /// is allowed, and repeated commas are ignored, eg. `all(unix,,)`.
+    #[default]
+    Strict,
+    /// Accepts what rustc accepts in a `#[cfg()]` attribute. Any name is
+    /// accepted with or without a value, and predicates that would be
+    /// rejected by [`Self::Strict`] are kept as a [`Predicate::Flag`](crate::Predicate::Flag)
+    /// or [`Predicate::KeyValue`](crate::Predicate::KeyValue). Only rustc's
+    /// `Pattern_White_Space` characters are whitespace. Escape sequences in
+    /// values are not supported.
+    Rustc,
+    /// Accepts what Cargo accepts in a `[target.'cfg()'.dependencies]` table.
+    /// Names are accepted the same as [`Self::Rustc`], but spaces are the
+    /// only whitespace, `version()` is not supported, and trailing commas are
+    /// only allowed in `all()` and `any()`.
+    Cargo,
+}
+
+/// Options that control how strictly [`Expression::parse_with_options`]
+/// validates an expression
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The rules for which expressions are valid
+    pub dialect: Dialect,
+    /// Rejects any `target_arch`, `target_os`, `target_env`, `target_vendor`,
+    /// `target_family`, `target_abi` or `panic` value that isn't used by at
+    /// least one of the [builtin](crate::targets::ALL_BUILTINS) targets with
+    /// [`Reason::UnknownBuiltin`]
+    ///
+    /// This catches typos such as `target_os = "linuxx"`, but also rejects
+    /// values that are only used by custom targets, or by targets that were
+    /// added to a newer version of rustc than the builtins were generated from.
+    pub strict: bool,
+}
+
+impl ParseOptions {
+    /// Options with [`Self::strict`] enabled
+    pub fn strict() -> Self {
+        Self {
+            strict: true,
+            ..Self::default()
+        }
+    }
+}
+
 impl Expression {
     /// Given a `cfg()` expression (the cfg( and ) are optional), attempts to
     /// parse it into a form where it can be evaluated
@@ -15,7 +66,24 @@ impl Expression {
     /// assert!(cfg_expr::Expression::parse(r#"cfg(all(unix, target_arch = "x86_64"))"#).is_ok());
     /// ```
     pub fn parse(original: &str) -> Result<Self, ParseError> {
-        let lexer = Lexer::new(original);
+        Self::parse_with_options(original, ParseOptions::default())
+    }
+
+    /// Parses a `cfg()` expression the same as [`Self::parse`], with the
+    /// additional validation enabled by the options
+    ///
+    /// ```
+    /// use cfg_expr::{error::Reason, expr::ParseOptions, Expression};
+    ///
+    /// let expr = r#"all(unix, target_os = "linuxx")"#;
+    /// assert!(Expression::parse(expr).is_ok());
+    ///
+    /// let err = Expression::parse_with_options(expr, ParseOptions::strict()).unwrap_err();
+    /// assert_eq!(err.reason, Reason::UnknownBuiltin);
+    /// assert_eq!(&expr[err.span], "linuxx");
+    /// ```
+    pub fn parse_with_options(original: &str, options: ParseOptions) -> Result<Self, ParseError> {
+        let lexer = Lexer::with_dialect(original, options.dialect);
 
         // The lexer automatically trims any cfg( ), so reacquire
         // the string before we start walking tokens
@@ -26,7 +94,7 @@ impl Expression {
             func: Func,
             parens_index: usize,
             span: std::ops::Range<usize>,
-            predicates: SmallVec<[InnerPredicate; 5]>,
+            num_predicates: usize,
             nest_level: u8,
         }
 
@@ -36,9 +104,9 @@ impl Expression {
         // Keep track of the last token to simplify validation of the token stream
         let mut last_token: Option<Token<'_>> = None;
 
-        let parse_predicate = |key: (&str, std::ops::Range<usize>),
-                               val: Option<(&str, std::ops::Range<usize>)>|
-         -> Result<InnerPredicate, ParseError> {
+        let parse_typed = |key: (&str, std::ops::Range<usize>),
+                           val: Option<(&str, std::ops::Range<usize>)>|
+         -> Result<ExprNode, ParseError> {
             // Warning: It is possible for arbitrarily-set configuration
             // options to have the same value as compiler-set configuration
             // options. For example, it is possible to do rustc --cfg "unix" program.rs
@@ -61,12 +129,47 @@ impl Expression {
                 };
             }
 
+            let spans = predicate_spans(&key.1, val.as_ref().map(|(_, vspan)| vspan));
+
             let span = key.1;
             let key = key.0;
 
-            use super::{InnerTarget, Which};
+            // Parses the value of a predicate that only has a fixed set of
+            // values, eg. `sanitize = "address"`
+            macro_rules! known_val {
+                ($expected:expr) => {
+                    match val {
+                        Some((val, vspan)) => val.parse().map_err(|reason| ParseError {
+                            original: original.to_owned(),
+                            span: vspan,
+                            reason,
+                        })?,
+                        None => {
+                            return Err(ParseError {
+                                original: original.to_owned(),
+                                span,
+                                reason: Reason::Unexpected(&[$expected]),
+                            });
+                        }
+                    }
+                };
+            }
 
-            Ok(match key {
+            // Raw identifiers are treated the same as their non-raw equivalent,
+            // other than the keywords, which are otherwise not valid keys
+            let (raw, key) = match key.strip_prefix("r#") {
+                Some(key) => (true, key),
+                None => (false, key),
+            };
+
+            use super::InnerTarget;
+
+            let pred = match key {
+                // The boolean literals are not predicates, but constants
+                "true" | "false" if !raw => {
+                    err_if_val!();
+                    return Ok(ExprNode::Literal(key == "true"));
+                }
                 // These are special cases in the cfg language that are
                 // semantically the same as `target_family = "<family>"`,
                 // so we just make them not special
@@ -78,7 +181,7 @@ impl Expression {
 
                     InnerPredicate::Target(InnerTarget {
                         which: Which::Family,
-                        span: Some(span),
+                        span: Some(if raw { span.start + 2..span.end } else { span }),
                     })
                 }
                 "test" => {
@@ -93,6 +196,31 @@ impl Expression {
                     err_if_val!();
                     InnerPredicate::ProcMacro
                 }
+                "overflow_checks" => {
+                    err_if_val!();
+                    InnerPredicate::OverflowChecks
+                }
+                "ub_checks" => {
+                    err_if_val!();
+                    InnerPredicate::UbChecks
+                }
+                "doctest" => {
+                    err_if_val!();
+                    InnerPredicate::Doctest
+                }
+                "miri" => {
+                    err_if_val!();
+                    InnerPredicate::Miri
+                }
+                "contract_checks" => {
+                    err_if_val!();
+                    InnerPredicate::ContractChecks
+                }
+                "sanitize" => InnerPredicate::Sanitize(known_val!("= \"<sanitizer>\"")),
+                "fmt_debug" => InnerPredicate::FmtDebug(known_val!("= \"<fmt_debug>\"")),
+                "relocation_model" => {
+                    InnerPredicate::RelocationModel(known_val!("= \"<relocation_model>\""))
+                }
                 "feature" => {
                     // rustc allows bare feature without a value, but the only way
                     // such a predicate would ever evaluate to true would be if they
@@ -110,10 +238,20 @@ impl Expression {
                     }
                 }
                 "panic" => match val {
-                    Some((_, vspan)) => InnerPredicate::Target(InnerTarget {
-                        which: Which::Panic,
-                        span: Some(vspan),
-                    }),
+                    Some((val, vspan)) => {
+                        if options.strict && !is_builtin(&Which::Panic, val) {
+                            return Err(ParseError {
+                                original: original.to_owned(),
+                                span: vspan,
+                                reason: Reason::UnknownBuiltin,
+                            });
+                        }
+
+                        InnerPredicate::Target(InnerTarget {
+                            which: Which::Panic,
+                            span: Some(vspan),
+                        })
+                    }
                     None => {
                         return Err(ParseError {
                             original: original.to_owned(),
@@ -122,6 +260,24 @@ impl Expression {
                         });
                     }
                 },
+                // Unlike the other target predicates, these don't have a value
+                "target_thread_local"
+                | "target_has_reliable_f16"
+                | "target_has_reliable_f16_math"
+                | "target_has_reliable_f128"
+                | "target_has_reliable_f128_math" => {
+                    err_if_val!();
+
+                    let which = match &key[7..] {
+                        "thread_local" => Which::ThreadLocal,
+                        "has_reliable_f16" => Which::HasReliableF16,
+                        "has_reliable_f16_math" => Which::HasReliableF16Math,
+                        "has_reliable_f128" => Which::HasReliableF128,
+                        _ => Which::HasReliableF128Math,
+                    };
+
+                    InnerPredicate::Target(InnerTarget { which, span: None })
+                }
                 target_key if key.starts_with("target_") => {
                     let (val, vspan) = match val {
                         None => {
@@ -155,7 +311,10 @@ impl Expression {
                                 });
                             }
 
-                            return Ok(InnerPredicate::TargetFeature(vspan));
+                            return Ok(ExprNode::Predicate(
+                                InnerPredicate::TargetFeature(vspan),
+                                spans,
+                            ));
                         }
                         "os" => tp!(Os),
                         "family" => tp!(Family),
@@ -176,6 +335,26 @@ impl Expression {
                             })?),
                             span: None,
                         },
+                        "has_atomic_load_store" => InnerTarget {
+                            which: Which::HasAtomicLoadStore(val.parse().map_err(|_err| {
+                                ParseError {
+                                    original: original.to_owned(),
+                                    span: vspan,
+                                    reason: Reason::InvalidHasAtomic,
+                                }
+                            })?),
+                            span: None,
+                        },
+                        "has_atomic_equal_alignment" => InnerTarget {
+                            which: Which::HasAtomicEqualAlignment(val.parse().map_err(|_err| {
+                                ParseError {
+                                    original: original.to_owned(),
+                                    span: vspan,
+                                    reason: Reason::InvalidHasAtomic,
+                                }
+                            })?),
+                            span: None,
+                        },
                         "pointer_width" => InnerTarget {
                             which: Which::PointerWidth(val.parse().map_err(|_err| ParseError {
                                 original: original.to_owned(),
@@ -190,6 +369,7 @@ impl Expression {
                                 original: original.to_owned(),
                                 span,
                                 reason: Reason::Unexpected(&[
+                                    "target_abi",
                                     "target_arch",
                                     "target_feature",
                                     "target_os",
@@ -197,27 +377,73 @@ impl Expression {
                                     "target_env",
                                     "target_endian",
                                     "target_has_atomic",
+                                    "target_has_atomic_load_store",
+                                    "target_has_atomic_equal_alignment",
                                     "target_pointer_width",
                                     "target_vendor",
+                                    "target_thread_local",
+                                    "target_has_reliable_f16",
+                                    "target_has_reliable_f16_math",
+                                    "target_has_reliable_f128",
+                                    "target_has_reliable_f128_math",
                                 ]),
                             });
                         }
                     };
 
+                    if options.strict && !is_builtin(&tp.which, val) {
+                        return Err(ParseError {
+                            original: original.to_owned(),
+                            span: tp.span.unwrap_or_default(),
+                            reason: Reason::UnknownBuiltin,
+                        });
+                    }
+
                     InnerPredicate::Target(tp)
                 }
+                // The prefix isn't part of the name, `r#foo` is the same as `foo`
                 _other => InnerPredicate::Other {
-                    identifier: span,
+                    identifier: if raw { span.start + 2..span.end } else { span },
                     value: val.map(|(_, span)| span),
                 },
-            })
+            };
+
+            Ok(ExprNode::Predicate(pred, spans))
+        };
+
+        // rustc and Cargo accept any name, with or without a value, so in
+        // their dialects a predicate we would otherwise reject is kept as a
+        // plain flag or key-value instead
+        let parse_predicate = |key: (&str, std::ops::Range<usize>),
+                               val: Option<(&str, std::ops::Range<usize>)>|
+         -> Result<ExprNode, ParseError> {
+            let is_literal = matches!(key.0, "true" | "false");
+            let raw = key.0.starts_with("r#");
+            let spans = predicate_spans(&key.1, val.as_ref().map(|(_, vspan)| vspan));
+
+            match parse_typed(key, val) {
+                Err(err)
+                    if options.dialect != Dialect::Strict
+                        && !is_literal
+                        && err.reason != Reason::UnknownBuiltin =>
+                {
+                    Ok(ExprNode::Predicate(
+                        InnerPredicate::Other {
+                            identifier: spans.key.start + if raw { 2 } else { 0 }..spans.key.end,
+                            value: spans.value.clone(),
+                        },
+                        spans,
+                    ))
+                }
+                res => res,
+            }
         };
 
         macro_rules! token_err {
             ($span:expr) => {{
                 let expected: &[&str] = match last_token {
                     None => &["<key>", "all", "any", "not"],
-                    Some(Token::All | Token::Any | Token::Not) => &["("],
+                    Some(Token::All | Token::Any | Token::Not | Token::Version) => &["("],
                     Some(Token::CloseParen) => &[")", ","],
                     Some(Token::Comma) => &[")", "<key>"],
                     Some(Token::Equals) => &["\""],
@@ -239,10 +465,95 @@ impl Expression {
 
         let mut root_predicate_count = 0;
 
+        // The `version()` predicate that is currently being parsed, unlike
+        // `all()`, `any()`, and `not()`, its only argument is a single value
+        // string, so we handle it separately from the function stack
+        struct VersionPred<'a> {
+            start: usize,
+            parens_index: Option<usize>,
+            value: Option<(&'a str, std::ops::Range<usize>)>,
+        }
+
+        let mut version: Option<VersionPred<'_>> = None;
+
+        // Cargo only allows a trailing comma in `all()` and `any()`
+        let mut trailing_comma = None;
+
         // Basic implementation of the https://en.wikipedia.org/wiki/Shunting-yard_algorithm
         'outer: for lt in lexer {
             let lt = lt?;
+            let after_comma = trailing_comma.take();
+
+            if let Some(vp) = &mut version {
+                match (&lt.token, &vp.parens_index, &vp.value) {
+                    (Token::OpenParen, None, _) => vp.parens_index = Some(lt.span.start),
+                    (Token::Value(v), Some(_), None) => {
+                        vp.value = Some((v, lt.span.start + 1..lt.span.end - 1));
+                    }
+                    (Token::CloseParen, _, Some((val, vspan))) => {
+                        let version = val.parse().map_err(|reason| ParseError {
+                            original: original.to_owned(),
+                            span: vspan.clone(),
+                            reason,
+                        })?;
+
+                        let node = ExprNode::Predicate(
+                            InnerPredicate::Version {
+                                version,
+                                span: vspan.clone(),
+                            },
+                            PredicateSpans {
+                                span: vp.start..lt.span.end,
+                                key: vp.start..vp.start + "version".len(),
+                                value: Some(vspan.clone()),
+                            },
+                        );
+
+                        if let Some(func) = func_stack.last_mut() {
+                            func.num_predicates += 1;
+                        } else {
+                            root_predicate_count += 1;
+                        }
+
+                        expr_queue.push(node);
+                    }
+                    (_, None, _) => token_err!(lt.span),
+                    (_, Some(_), None) => {
+                        return Err(ParseError {
+                            original: original.to_owned(),
+                            span: lt.span,
+                            reason: Reason::Unexpected(&["\"<version>\""]),
+                        });
+                    }
+                    (_, Some(_), Some(_)) => {
+                        return Err(ParseError {
+                            original: original.to_owned(),
+                            span: lt.span,
+                            reason: Reason::Unexpected(&[")"]),
+                        });
+                    }
+                }
+
+                if matches!(lt.token, Token::CloseParen) {
+                    version = None;
+                }
+
+                last_token = Some(lt.token);
+                continue;
+            }
+
             match &lt.token {
+                Token::Version => {
+                    if matches!(last_token, None | Some(Token::OpenParen | Token::Comma)) {
+                        version = Some(VersionPred {
+                            start: lt.span.start,
+                            parens_index: None,
+                            value: None,
+                        });
+                    } else {
+                        token_err!(lt.span)
+                    }
+                }
                 Token::Key(k) => {
                     if matches!(last_token, None | Some(Token::OpenParen | Token::Comma)) {
                         pred_key = Some((k, lt.span.clone()));
@@ -286,7 +597,7 @@ impl Expression {
                             func: new_fn,
                             span: lt.span,
                             parens_index: 0,
-                            predicates: SmallVec::new(),
+                            num_predicates: 0,
                             nest_level: 0,
                         });
                     } else {
@@ -315,7 +626,7 @@ impl Expression {
 
                             // In this context, the boolean to int conversion is confusing.
                             #[allow(clippy::bool_to_int_with_if)]
-                            let num_predicates = top.predicates.len()
+                            let num_predicates = top.num_predicates
                                 + if key.is_some() { 1 } else { 0 }
                                 + top.nest_level as usize;
 
@@ -323,6 +634,16 @@ impl Expression {
                                 Func::All(_) => Func::All(num_predicates),
                                 Func::Any(_) => Func::Any(num_predicates),
                                 Func::Not => {
+                                    if let Some(span) =
+                                        after_comma.filter(|_| options.dialect == Dialect::Cargo)
+                                    {
+                                        return Err(ParseError {
+                                            original: original.to_owned(),
+                                            span,
+                                            reason: Reason::Unexpected(&[")"]),
+                                        });
+                                    }
+
                                     // not() doesn't take a predicate list, but only a single predicate,
                                     // so ensure we have exactly 1
                                     if num_predicates != 1 {
@@ -337,13 +658,8 @@ impl Expression {
                                 }
                             };
 
-                            for pred in top.predicates {
-                                expr_queue.push(ExprNode::Predicate(pred));
-                            }
-
                             if let Some(key) = key {
-                                let inner_pred = parse_predicate(key, val)?;
-                                expr_queue.push(ExprNode::Predicate(inner_pred));
+                                expr_queue.push(parse_predicate(key, val)?);
                             }
 
                             expr_queue.push(ExprNode::Fn(func));
@@ -368,22 +684,30 @@ impl Expression {
                         None | Some(
                             Token::OpenParen | Token::All | Token::Any | Token::Not | Token::Equals
                         )
-                    ) {
+                    ) || (options.dialect != Dialect::Strict
+                        && matches!(last_token, Some(Token::Comma)))
+                    {
                         token_err!(lt.span)
                     } else {
                         let key = pred_key.take();
                         let val = pred_val.take();
 
-                        let inner_pred = key.map(|key| parse_predicate(key, val)).transpose()?;
+                        trailing_comma = Some(lt.span.clone());
 
-                        match (inner_pred, func_stack.last_mut()) {
-                            (Some(pred), Some(func)) => {
-                                func.predicates.push(pred);
+                        let node = key.map(|key| parse_predicate(key, val)).transpose()?;
+
+                        match (node, func_stack.last_mut()) {
+                            (Some(node), Some(func)) => {
+                                // Predicates are pushed as soon as they are complete
+                                // so that the expression is kept in source order
+                                func.num_predicates += 1;
+
+                                expr_queue.push(node);
                             }
-                            (Some(pred), None) => {
+                            (Some(node), None) => {
                                 root_predicate_count += 1;
 
-                                expr_queue.push(ExprNode::Predicate(pred));
+                                expr_queue.push(node);
                             }
                             _ => {}
                         }
@@ -402,6 +726,14 @@ impl Expression {
             });
         }
 
+        if let Some(vp) = version {
+            return Err(ParseError {
+                original: original.to_owned(),
+                span: vp.parens_index.unwrap_or(vp.start)..original.len(),
+                reason: Reason::UnclosedParens,
+            });
+        }
+
         // If we still have functions on the stack, it means we have an unclosed parens
         if let Some(top) = func_stack.pop() {
             if top.parens_index != 0 {
@@ -423,7 +755,15 @@ impl Expression {
 
             if let Some(key) = key {
                 root_predicate_count += 1;
-                expr_queue.push(ExprNode::Predicate(parse_predicate(key, val)?));
+                expr_queue.push(parse_predicate(key, val)?);
+            }
+
+            if let Some(span) = trailing_comma.filter(|_| options.dialect == Dialect::Cargo) {
+                return Err(ParseError {
+                    original: original.to_owned(),
+                    span,
+                    reason: Reason::Unexpected(&[]),
+                });
             }
 
             if expr_queue.is_empty() {
@@ -447,3 +787,39 @@ impl Expression {
         }
     }
 }
+
+/// The spans of a key or key-value predicate, the value span excludes the
+/// quotes, so the predicate ends just after it
+fn predicate_spans(
+    key: &std::ops::Range<usize>,
+    value: Option<&std::ops::Range<usize>>,
+) -> PredicateSpans {
+    PredicateSpans {
+        span: key.start..value.map_or(key.end, |value| value.end + 1),
+        key: key.clone(),
+        value: value.cloned(),
+    }
+}
+
+/// Checks if the value of a target predicate is used by any of the builtin
+/// targets, predicates whose values are already validated by the parser are
+/// always considered builtin
+fn is_builtin(which: &Which, val: &str) -> bool {
+    use crate::{
+        expr::{TargetMatcher, TargetPredicate as TP},
+        targets::{self as targ, ALL_BUILTINS},
+    };
+
+    let tp = match which {
+        Which::Abi => TP::Abi(targ::Abi::new(val.to_owned())),
+        Which::Arch => TP::Arch(targ::Arch::new(val.to_owned())),
+        Which::Env => TP::Env(targ::Env::new(val.to_owned())),
+        Which::Family => TP::Family(targ::Family::new(val.to_owned())),
+        Which::Os => TP::Os(targ::Os::new(val.to_owned())),
+        Which::Panic => TP::Panic(targ::Panic::new(val.to_owned())),
+        Which::Vendor => TP::Vendor(targ::Vendor::new(val.to_owned())),
+        _ => return true,
+    };
+
+    ALL_BUILTINS.iter().any(|ti| ti.matches(&tp))
+}
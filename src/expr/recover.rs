@@ -0,0 +1,366 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::{
+        CfgTree, Dialect, Expression, ParseOptions,
+        lexer::{Lexer, Token},
+    },
+};
+use std::ops::Range;
+
+/// The result of [`Expression::parse_recovering`]
+#[derive(Debug)]
+pub struct Recovered {
+    /// A best-effort expression made up of every part of the original string
+    /// that could be parsed, or `None` if nothing could be
+    ///
+    /// If there were any errors, this is a canonical rendering of the parts
+    /// that were kept, so its spans don't correspond to the original string.
+    pub expr: Option<Expression>,
+    /// Every error in the original string, in source order. This is empty
+    /// only if [`Expression::parse`] succeeds.
+    pub errors: Vec<ParseError>,
+}
+
+impl Expression {
+    /// Parses a `cfg()` expression like [`Self::parse`], but rather than
+    /// stopping at the first error, keeps going to report every error at once
+    ///
+    /// Unbalanced parens are closed or skipped, missing commas are assumed,
+    /// and invalid predicates are left out of the [`Recovered::expr`].
+    ///
+    /// ```
+    /// use cfg_expr::{error::Reason, Expression};
+    ///
+    /// let recovered = Expression::parse_recovering(r#"all(unix target_os = "linux", sanitize = "adress""#);
+    ///
+    /// let reasons: Vec<_> = recovered.errors.iter().map(|err| &err.reason).collect();
+    /// assert_eq!(reasons.len(), 3);
+    /// assert_eq!(reasons[0], &Reason::UnclosedParens);
+    /// assert_eq!(reasons[1], &Reason::Unexpected(&[",", ")"]));
+    ///
+    /// assert_eq!(recovered.expr.unwrap().original(), r#"all(unix, target_os = "linux")"#);
+    /// ```
+    pub fn parse_recovering(original: &str) -> Recovered {
+        Self::parse_recovering_with_options(original, ParseOptions::default())
+    }
+
+    /// Parses a `cfg()` expression like [`Self::parse_with_options`], but
+    /// keeps going to report every error at once like [`Self::parse_recovering`]
+    ///
+    /// ```
+    /// use cfg_expr::{expr::{Dialect, ParseOptions}, Expression};
+    ///
+    /// let options = ParseOptions { dialect: Dialect::Rustc, ..Default::default() };
+    /// let recovered = Expression::parse_recovering_with_options("all(unix,, feature = \"std\"", options);
+    ///
+    /// // Unlike this crate, rustc doesn't ignore repeated commas
+    /// assert_eq!(recovered.errors.len(), 2);
+    /// assert_eq!(recovered.expr.unwrap().original(), r#"all(unix, feature = "std")"#);
+    /// ```
+    pub fn parse_recovering_with_options(original: &str, options: ParseOptions) -> Recovered {
+        let strict_err = match Self::parse_with_options(original, options) {
+            Ok(expr) => {
+                return Recovered {
+                    expr: Some(expr),
+                    errors: Vec::new(),
+                };
+            }
+            Err(err) => err,
+        };
+
+        let lexer = Lexer::with_dialect(original, options.dialect);
+        let original = lexer.inner;
+
+        let mut parser = Parser {
+            original,
+            options,
+            tokens: Vec::new(),
+            pos: 0,
+            errors: Vec::new(),
+        };
+
+        for lt in lexer {
+            match lt {
+                Ok(lt) => parser.tokens.push((lt.token, lt.span)),
+                Err(err) => parser.errors.push(err),
+            }
+        }
+
+        let mut roots = Vec::new();
+        let mut trailing_comma = None;
+        while let Some((tok, span)) = parser.peek() {
+            if *tok == Token::Comma {
+                let span = span.clone();
+                parser.error(span, Reason::Unexpected(&["<key>", "all", "any", "not"]));
+                parser.pos += 1;
+                continue;
+            }
+
+            if let Some(tree) = parser.expr() {
+                roots.push(tree);
+            }
+
+            trailing_comma = None;
+            match parser.peek() {
+                Some((Token::Comma, _)) => trailing_comma = parser.skip_commas(),
+                Some((_, span)) => {
+                    let span = span.clone();
+                    parser.error(span, Reason::Unexpected(&[",", ")"]));
+                }
+                None => {}
+            }
+        }
+
+        if let Some(span) = trailing_comma.filter(|_| options.dialect == Dialect::Cargo) {
+            parser.error(span, Reason::Unexpected(&[]));
+        }
+
+        if roots.len() > 1 {
+            parser.error(0..original.len(), Reason::MultipleRootPredicates);
+        }
+
+        let mut errors = parser.errors;
+
+        // The strict parser is the source of truth for whether the string is
+        // valid, so make sure its error is always reported
+        if errors.is_empty() {
+            errors.push(strict_err);
+        }
+
+        errors.sort_by_key(|err| (err.span.start, err.span.end));
+        errors.dedup();
+
+        Recovered {
+            expr: roots.into_iter().next().map(|root| {
+                Expression::try_from(root).expect("parsed predicates can be parsed again")
+            }),
+            errors,
+        }
+    }
+}
+
+/// A recursive descent parser over the lexed tokens, which records errors and
+/// skips over them
+struct Parser<'a> {
+    original: &'a str,
+    options: ParseOptions,
+    tokens: Vec<(Token<'a>, Range<usize>)>,
+    pos: usize,
+    errors: Vec<ParseError>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&(Token<'a>, Range<usize>)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<(Token<'a>, Range<usize>)> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    /// Consumes the next token if it's the expected one
+    fn eat(&mut self, expected: &Token<'_>) -> Option<Range<usize>> {
+        match self.peek() {
+            Some((tok, span)) if tok == expected => {
+                let span = span.clone();
+                self.pos += 1;
+                Some(span)
+            }
+            _ => None,
+        }
+    }
+
+    /// The span of the next token, or the end of the string
+    fn next_span(&self) -> Range<usize> {
+        self.peek()
+            .map_or(self.original.len()..self.original.len(), |(_, span)| {
+                span.clone()
+            })
+    }
+
+    /// Consumes the comma after an argument, along with any repeated commas,
+    /// which are ignored by [`Dialect::Strict`] and errors in the others,
+    /// returning the span of the first comma
+    fn skip_commas(&mut self) -> Option<Range<usize>> {
+        let first = self.eat(&Token::Comma)?;
+
+        while let Some(span) = self.eat(&Token::Comma) {
+            if self.options.dialect != Dialect::Strict {
+                self.error(span, Reason::Unexpected(&[")", "<key>"]));
+            }
+        }
+
+        Some(first)
+    }
+
+    fn error(&mut self, span: Range<usize>, reason: Reason) {
+        debug_assert!(
+            self.original.get(span.clone()).is_some(),
+            "{span:?} is not a valid span of {:?}",
+            self.original
+        );
+
+        self.errors.push(ParseError {
+            original: self.original.to_owned(),
+            span,
+            reason,
+        });
+    }
+
+    /// Parses a single predicate or function, always consuming at least one
+    /// token
+    fn expr(&mut self) -> Option<CfgTree> {
+        let (tok, span) = self.next()?;
+
+        match tok {
+            Token::All | Token::Any | Token::Not => {
+                let Some(open) = self.eat(&Token::OpenParen) else {
+                    let next = self.next_span();
+                    self.error(next, Reason::Unexpected(&["("]));
+                    return None;
+                };
+
+                let (children, count, end, trailing_comma) = self.list(open);
+
+                match tok {
+                    Token::All => Some(CfgTree::All(children)),
+                    Token::Any => Some(CfgTree::Any(children)),
+                    _ => {
+                        if let Some(span) =
+                            trailing_comma.filter(|_| self.options.dialect == Dialect::Cargo)
+                        {
+                            self.error(span, Reason::Unexpected(&[")"]));
+                        }
+
+                        if count != 1 {
+                            self.error(span.start..end, Reason::InvalidNot(count));
+                        }
+
+                        children
+                            .into_iter()
+                            .next()
+                            .map(|child| CfgTree::Not(Box::new(child)))
+                    }
+                }
+            }
+            Token::Key(_) => {
+                let mut end = span.end;
+                if self.eat(&Token::Equals).is_some() {
+                    if let Some((Token::Value(_), vspan)) = self.peek() {
+                        end = vspan.end;
+                        self.pos += 1;
+                    } else {
+                        let next = self.next_span();
+
+                        // The value might be missing because it couldn't be
+                        // lexed, in which case it has already been reported
+                        if !self
+                            .errors
+                            .iter()
+                            .any(|err| err.span.start <= next.start && next.start <= err.span.end)
+                        {
+                            self.error(next, Reason::Unexpected(&["\""]));
+                        }
+                        return None;
+                    }
+                }
+
+                self.predicate(span.start..end)
+            }
+            Token::Version => {
+                // Let the strict parser report any problems with the version
+                let mut end = span.end;
+                for expected in [Token::OpenParen, Token::Value(""), Token::CloseParen] {
+                    match self.peek() {
+                        Some((tok, tspan))
+                            if std::mem::discriminant(tok) == std::mem::discriminant(&expected) =>
+                        {
+                            end = tspan.end;
+                            self.pos += 1;
+                        }
+                        _ => break,
+                    }
+                }
+
+                self.predicate(span.start..end)
+            }
+            Token::CloseParen => {
+                self.error(span, Reason::UnopenedParens);
+                None
+            }
+            _ => {
+                self.error(span, Reason::Unexpected(&["<key>", "all", "any", "not"]));
+                None
+            }
+        }
+    }
+
+    /// Parses the comma separated arguments of a function after its opening
+    /// parens, returning the successfully parsed arguments, the number of
+    /// arguments, the end of the closing parens, and the span of the trailing
+    /// comma, if any
+    fn list(&mut self, open: Range<usize>) -> (Vec<CfgTree>, usize, usize, Option<Range<usize>>) {
+        let mut children = Vec::new();
+        let mut count = 0;
+        let mut trailing_comma = None;
+
+        loop {
+            match self.peek() {
+                None => {
+                    let end = self.original.len();
+                    self.error(open.start..end, Reason::UnclosedParens);
+                    return (children, count, end, None);
+                }
+                Some((Token::CloseParen, span)) => {
+                    let end = span.end;
+                    self.pos += 1;
+                    return (children, count, end, trailing_comma);
+                }
+                Some((Token::Comma, span)) => {
+                    let span = span.clone();
+                    self.error(
+                        span,
+                        Reason::Unexpected(&["<key>", ")", "all", "any", "not"]),
+                    );
+                    self.pos += 1;
+                    continue;
+                }
+                Some(_) => {}
+            }
+
+            count += 1;
+            if let Some(child) = self.expr() {
+                children.push(child);
+            }
+
+            trailing_comma = None;
+            match self.peek() {
+                Some((Token::Comma, _)) => trailing_comma = self.skip_commas(),
+                Some((Token::CloseParen, _)) | None => {}
+                // Assume the comma is missing and carry on
+                Some((_, span)) => {
+                    let span = span.clone();
+                    self.error(span, Reason::Unexpected(&[",", ")"]));
+                }
+            }
+        }
+    }
+
+    /// Validates a single predicate with the strict parser
+    fn predicate(&mut self, span: Range<usize>) -> Option<CfgTree> {
+        let text = &self.original[span.clone()];
+
+        match Expression::parse_with_options(text, self.options) {
+            Ok(expr) => Some(expr.to_tree()),
+            Err(err) => {
+                // The span is relative to the string stored in the error,
+                // which the lexer may have trimmed out of the predicate
+                let offset = span.start + text.find(err.original.as_str()).unwrap_or_default();
+                self.error(err.span.start + offset..err.span.end + offset, err.reason);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use crate::expr::{ExprNode, Expression, Func, Logic, OwnedPredicate, Predicate};
+
+/// The maximum number of variables for which every assignment is checked,
+/// rather than searching for a satisfying assignment
+const TRUTH_TABLE_MAX_VARS: usize = 10;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Node {
+    /// A predicate whose value is known up front
+    Const(bool),
+    /// A predicate that can take either value
+    Var(usize),
+    Fn(Func),
+}
+
+/// A propositional formula, where each unique predicate is an independent
+/// variable, stored in postfix order just like [`Expression`]
+#[derive(Default)]
+pub(crate) struct Formula {
+    pub(crate) nodes: Vec<Node>,
+    pub(crate) vars: Vec<OwnedPredicate>,
+}
+
+impl Formula {
+    /// Appends an expression to the formula, every predicate is a variable
+    pub(crate) fn push_expr(&mut self, expr: &Expression) {
+        self.push_expr_with(expr, |_| None);
+    }
+
+    /// Appends an expression to the formula, using `fixed` to determine the
+    /// predicates that have a known value rather than being a variable
+    pub(crate) fn push_expr_with(
+        &mut self,
+        expr: &Expression,
+        mut fixed: impl FnMut(&Predicate<'_>) -> Option<bool>,
+    ) {
+        for node in &expr.expr {
+            let node = match node {
+                ExprNode::Fn(func) => Node::Fn(*func),
+                ExprNode::Literal(value) => Node::Const(*value),
+                ExprNode::Predicate(pred, _) => {
+                    let pred = pred.to_pred(&expr.original);
+
+                    match fixed(&pred) {
+                        Some(value) => Node::Const(value),
+                        None => Node::Var(self.intern(pred.into())),
+                    }
+                }
+            };
+
+            self.nodes.push(node);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn push_fn(&mut self, func: Func) {
+        self.nodes.push(Node::Fn(func));
+    }
+
+    fn intern(&mut self, pred: OwnedPredicate) -> usize {
+        if let Some(ind) = self.vars.iter().position(|v| *v == pred) {
+            ind
+        } else {
+            self.vars.push(pred);
+            self.vars.len() - 1
+        }
+    }
+
+    /// Evaluates the formula with the specified variable values
+    pub(crate) fn eval<T: Logic>(&self, mut var: impl FnMut(usize) -> T) -> T {
+        let mut result_stack = Vec::<T>::new();
+
+        for node in &self.nodes {
+            match node {
+                Node::Const(true) => result_stack.push(T::top()),
+                Node::Const(false) => result_stack.push(T::bottom()),
+                Node::Var(ind) => result_stack.push(var(*ind)),
+                Node::Fn(Func::All(count)) => {
+                    let mut result = T::top();
+                    for _ in 0..*count {
+                        result = result.and(result_stack.pop().unwrap());
+                    }
+                    result_stack.push(result);
+                }
+                Node::Fn(Func::Any(count)) => {
+                    let mut result = T::bottom();
+                    for _ in 0..*count {
+                        result = result.or(result_stack.pop().unwrap());
+                    }
+                    result_stack.push(result);
+                }
+                Node::Fn(Func::Not) => {
+                    let r = result_stack.pop().unwrap();
+                    result_stack.push(r.not());
+                }
+            }
+        }
+
+        result_stack.pop().unwrap()
+    }
+
+    /// Determines if there is any assignment of the variables for which the
+    /// formula is true
+    pub(crate) fn is_satisfiable(&self) -> bool {
+        // A variable that only ever occurs with one polarity can always be
+        // given the value that makes its occurrences true, as that can never
+        // turn a satisfying assignment into an unsatisfying one, so only the
+        // remaining variables need to be searched
+        let mut assignment: Vec<_> = self
+            .polarities()
+            .into_iter()
+            .map(|polarity| match polarity {
+                (true, true) => None,
+                (positive, _) => Some(positive),
+            })
+            .collect();
+        let free: Vec<_> = (0..assignment.len())
+            .filter(|ind| assignment[*ind].is_none())
+            .collect();
+
+        if free.len() <= TRUTH_TABLE_MAX_VARS {
+            (0u32..1 << free.len()).any(|mask| {
+                for (bit, ind) in free.iter().enumerate() {
+                    assignment[*ind] = Some(mask & (1 << bit) != 0);
+                }
+
+                self.eval(|ind| assignment[ind] == Some(true))
+            })
+        } else {
+            self.search(&mut assignment, &free)
+        }
+    }
+
+    /// Determines whether each variable occurs positively, ie. inside an even
+    /// number of `not()`s, and whether it occurs negatively
+    fn polarities(&self) -> Vec<(bool, bool)> {
+        let mut polarities = vec![(false, false); self.vars.len()];
+
+        // Walking the formula backwards visits each function before its
+        // arguments, so we can keep track of whether each of the functions
+        // that are currently open is negated, and how many arguments it has
+        // left
+        let mut open = Vec::<(bool, usize)>::new();
+
+        for node in self.nodes.iter().rev() {
+            while let Some((_, 0)) = open.last() {
+                open.pop();
+            }
+
+            let negated = open.last().is_some_and(|(negated, _)| *negated);
+            if let Some((_, remaining)) = open.last_mut() {
+                *remaining -= 1;
+            }
+
+            match node {
+                Node::Fn(Func::Not) => open.push((!negated, 1)),
+                Node::Fn(Func::All(count) | Func::Any(count)) => open.push((negated, *count)),
+                Node::Var(ind) if negated => polarities[*ind].1 = true,
+                Node::Var(ind) => polarities[*ind].0 = true,
+                Node::Const(_) => {}
+            }
+        }
+
+        polarities
+    }
+
+    /// Determines if the result of the formula depends on the value of the
+    /// specified variable, ie. there is some assignment of the other
+    /// variables where changing it changes the result
+    pub(crate) fn depends_on(&self, var: usize) -> bool {
+        let with = |value: bool| {
+            self.nodes.iter().map(move |node| match node {
+                Node::Var(ind) if *ind == var => Node::Const(value),
+                other => *other,
+            })
+        };
+
+        // f[var = true] XOR f[var = false]
+        let mut xor = Formula {
+            nodes: Vec::with_capacity(self.nodes.len() * 4 + 7),
+            vars: self.vars.clone(),
+        };
+
+        xor.nodes.extend(with(true));
+        xor.nodes.extend(with(false));
+        xor.push_fn(Func::Not);
+        xor.push_fn(Func::All(2));
+        xor.nodes.extend(with(true));
+        xor.push_fn(Func::Not);
+        xor.nodes.extend(with(false));
+        xor.push_fn(Func::All(2));
+        xor.push_fn(Func::Any(2));
+
+        xor.is_satisfiable()
+    }
+
+    /// Assigns each of the free variables in turn, using the three-valued
+    /// evaluation of the partial assignment to prune every branch whose
+    /// result is already known
+    fn search(&self, assignment: &mut [Option<bool>], free: &[usize]) -> bool {
+        if let Some(result) = self.eval(|ind| assignment[ind]) {
+            return result;
+        }
+
+        let (&next, rest) = free
+            .split_first()
+            .expect("the result is known once every variable is assigned");
+
+        [true, false].into_iter().any(|value| {
+            assignment[next] = Some(value);
+            let sat = self.search(assignment, rest);
+            assignment[next] = None;
+            sat
+        })
+    }
+}
+
+impl Expression {
+    /// Determines if this expression implies `other`, ie. that `other` is true
+    /// whenever this expression is true
+    ///
+    /// Each unique predicate is treated as an independent variable, so eg.
+    /// `target_os = "linux"` is not considered to exclude `target_os = "windows"`.
+    ///
+    /// This is a satisfiability check, so in the worst case it takes time
+    /// exponential in the number of unique predicates. Predicates that are
+    /// never negated, or always negated, are fixed up front, and the search
+    /// stops as soon as the result of a partial assignment is known, so cfg
+    /// expressions written by hand are fast, but an expression with hundreds
+    /// of predicates that each occur both inside and outside of `not()` may
+    /// not be.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let linux = Expression::parse(r#"all(unix, target_os = "linux")"#).unwrap();
+    /// let unix = Expression::parse("unix").unwrap();
+    ///
+    /// assert!(linux.implies(&unix));
+    /// assert!(!unix.implies(&linux));
+    /// ```
+    pub fn implies(&self, other: &Expression) -> bool {
+        // self -> other is valid iff self & !other is unsatisfiable
+        let mut formula = Formula::default();
+        formula.push_expr(self);
+        formula.push_expr(other);
+        formula.push_fn(Func::Not);
+        formula.push_fn(Func::All(2));
+
+        !formula.is_satisfiable()
+    }
+
+    /// Determines if this expression is logically equivalent to `other`, ie.
+    /// they evaluate to the same result for every value of their predicates
+    ///
+    /// Unlike [`PartialEq`], which compares the original strings, this
+    /// compares the meaning of the expressions. Each unique predicate is
+    /// treated as an independent variable, and the same worst case
+    /// complexity applies, as with [`Expression::implies`].
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let a = Expression::parse("not(any(unix, windows))").unwrap();
+    /// let b = Expression::parse("all(not(windows), not(unix))").unwrap();
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.is_equivalent(&b));
+    /// ```
+    pub fn is_equivalent(&self, other: &Expression) -> bool {
+        self.implies(other) && other.implies(self)
+    }
+}
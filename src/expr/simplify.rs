@@ -0,0 +1,160 @@
+use crate::expr::{CfgTree, ExprNode, Expression, Func};
+
+impl Expression {
+    /// Returns a simplified version of this expression that is logically
+    /// equivalent, treating each unique predicate as an independent variable
+    ///
+    /// The following rules are applied
+    ///
+    /// * Nested `all()` and `any()` are flattened into their parent, eg.
+    ///   `all(a, all(b, c))` => `all(a, b, c)`
+    /// * Double negations are removed, eg. `not(not(a))` => `a`
+    /// * Duplicate operands are removed, eg. `any(a, a)` => `a`
+    /// * The constants `all()` and `true`, and `any()` and `false`, are
+    ///   folded, eg. `all(a, any())` => `any()`, and `any(a, true)` => `all()`
+    /// * Absorption, eg. `all(a, any(a, b))` => `a`
+    /// * Complements, eg. `any(a, not(a))` => `all()`
+    /// * `all()` and `any()` with a single operand are replaced by that operand
+    ///
+    /// The [`Expression::original`] of the returned expression is its
+    /// canonical rendering.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse("all(unix, any(unix, windows), not(not(feature = \"a\")))").unwrap();
+    /// assert_eq!(expr.simplify().original(), "all(unix, feature = \"a\")");
+    /// ```
+    pub fn simplify(&self) -> Expression {
+        let mut stack = Vec::<CfgTree>::new();
+
+        for node in &self.expr {
+            match node {
+                ExprNode::Predicate(pred, _) => {
+                    stack.push(CfgTree::Pred(pred.to_pred(&self.original).into()));
+                }
+                // Literals are folded the same as the other constants
+                ExprNode::Literal(true) => stack.push(CfgTree::All(Vec::new())),
+                ExprNode::Literal(false) => stack.push(CfgTree::Any(Vec::new())),
+                ExprNode::Fn(Func::All(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    stack.push(simplify_list(Func::All(0), operands));
+                }
+                ExprNode::Fn(Func::Any(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    stack.push(simplify_list(Func::Any(0), operands));
+                }
+                ExprNode::Fn(Func::Not) => {
+                    let operand = stack.pop().unwrap();
+                    stack.push(simplify_not(operand));
+                }
+            }
+        }
+
+        Expression::try_from(stack.pop().unwrap()).expect("parsed predicates can be parsed again")
+    }
+}
+
+#[inline]
+fn is_const(tree: &CfgTree, value: bool) -> bool {
+    match tree {
+        CfgTree::All(ops) if value => ops.is_empty(),
+        CfgTree::Any(ops) if !value => ops.is_empty(),
+        _ => false,
+    }
+}
+
+fn simplify_not(operand: CfgTree) -> CfgTree {
+    match operand {
+        CfgTree::Not(inner) => *inner,
+        c if is_const(&c, true) => CfgTree::Any(Vec::new()),
+        c if is_const(&c, false) => CfgTree::All(Vec::new()),
+        other => CfgTree::Not(Box::new(other)),
+    }
+}
+
+/// Simplifies an `all()` or `any()` whose operands have already been simplified
+fn simplify_list(func: Func, operands: Vec<CfgTree>) -> CfgTree {
+    let is_all = matches!(func, Func::All(_));
+
+    let mut flat = Vec::with_capacity(operands.len());
+    for op in operands {
+        match op {
+            CfgTree::All(ops) if is_all => flat.extend(ops),
+            CfgTree::Any(ops) if !is_all => flat.extend(ops),
+            other => flat.push(other),
+        }
+    }
+
+    let mut ops = Vec::<CfgTree>::with_capacity(flat.len());
+    for op in flat {
+        // The dominating constant for this list, ie. false for all() and true
+        // for any(), means the entire list is that constant
+        if is_const(&op, !is_all) {
+            return op;
+        }
+
+        if !ops.contains(&op) {
+            ops.push(op);
+        }
+    }
+
+    // Complements, `all(a, not(a))` is always false, `any(a, not(a))` always true
+    let has_complement = ops.iter().any(|op| match op {
+        CfgTree::Not(inner) => ops.contains(inner),
+        _ => false,
+    });
+
+    if has_complement {
+        return if is_all {
+            CfgTree::Any(Vec::new())
+        } else {
+            CfgTree::All(Vec::new())
+        };
+    }
+
+    // Absorption, an operand can be removed if its terms are a superset of
+    // another operand's, eg. in `all(a, any(a, b))` `a` implies `any(a, b)`, and
+    // in `any(all(a, b), all(a, b, c))` `all(a, b, c)` implies `all(a, b)`
+    let terms = |tree: &CfgTree| -> Vec<CfgTree> {
+        match tree {
+            CfgTree::Any(ops) if is_all => ops.clone(),
+            CfgTree::All(ops) if !is_all => ops.clone(),
+            other => vec![other.clone()],
+        }
+    };
+
+    let op_terms: Vec<_> = ops.iter().map(terms).collect();
+    let mut keep = vec![true; ops.len()];
+
+    for i in 0..ops.len() {
+        for j in 0..ops.len() {
+            if i == j || !keep[j] {
+                continue;
+            }
+
+            let subset = op_terms[j].iter().all(|t| op_terms[i].contains(t));
+            let same_terms = subset && op_terms[i].len() == op_terms[j].len();
+
+            // If both have the same terms keep the first one
+            if subset && (!same_terms || j < i) {
+                keep[i] = false;
+                break;
+            }
+        }
+    }
+
+    let mut ops: Vec<_> = ops
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(op, keep)| keep.then_some(op))
+        .collect();
+
+    if ops.len() == 1 {
+        ops.pop().unwrap()
+    } else if is_all {
+        CfgTree::All(ops)
+    } else {
+        CfgTree::Any(ops)
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    error::ParseError,
+    expr::{ExprNode, Expression, Func, OwnedPredicate},
+};
+
+/// An owned tree representation of a `cfg()` expression
+///
+/// [`Expression`] stores the parsed expression in a compact form that is
+/// suited to evaluation, while the tree is suited to walking and rewriting
+/// the expression. Converting between the two is lossless, other than the
+/// tree -> [`Expression`] conversion producing the canonical rendering of
+/// the expression for [`Expression::original`], and failing if the tree
+/// contains a predicate that can't be rendered, eg. an empty flag.
+///
+/// ```
+/// use cfg_expr::{expr::{CfgTree, OwnedPredicate}, Expression};
+///
+/// let expr = Expression::parse("all(unix, not(feature = \"cool\"))").unwrap();
+/// let tree = CfgTree::from(&expr);
+///
+/// assert_eq!(
+///     tree,
+///     CfgTree::All(vec![
+///         CfgTree::Pred(OwnedPredicate::Target(cfg_expr::TargetPredicate::Family(
+///             cfg_expr::targets::Family::unix,
+///         ))),
+///         CfgTree::Not(Box::new(CfgTree::Pred(OwnedPredicate::Feature("cool".to_owned())))),
+///     ])
+/// );
+///
+/// assert_eq!(Expression::try_from(tree).unwrap().original(), expr.original());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CfgTree {
+    /// An `all()` of each of the child trees
+    All(Vec<CfgTree>),
+    /// An `any()` of each of the child trees
+    Any(Vec<CfgTree>),
+    /// A `not()` of the child tree
+    Not(Box<CfgTree>),
+    /// A single predicate
+    Pred(OwnedPredicate),
+    /// A `true` or `false` literal
+    Literal(bool),
+}
+
+impl Expression {
+    /// Converts the expression into a [`CfgTree`]
+    pub fn to_tree(&self) -> CfgTree {
+        let mut stack = Vec::new();
+
+        for node in &self.expr {
+            match node {
+                ExprNode::Predicate(pred, _) => {
+                    stack.push(CfgTree::Pred(pred.to_pred(&self.original).into()));
+                }
+                ExprNode::Literal(value) => stack.push(CfgTree::Literal(*value)),
+                ExprNode::Fn(Func::All(count)) => {
+                    let children = stack.split_off(stack.len() - count);
+                    stack.push(CfgTree::All(children));
+                }
+                ExprNode::Fn(Func::Any(count)) => {
+                    let children = stack.split_off(stack.len() - count);
+                    stack.push(CfgTree::Any(children));
+                }
+                ExprNode::Fn(Func::Not) => {
+                    let child = stack.pop().unwrap();
+                    stack.push(CfgTree::Not(Box::new(child)));
+                }
+            }
+        }
+
+        stack.pop().unwrap()
+    }
+}
+
+impl From<&Expression> for CfgTree {
+    fn from(expr: &Expression) -> Self {
+        expr.to_tree()
+    }
+}
+
+impl From<Expression> for CfgTree {
+    fn from(expr: Expression) -> Self {
+        expr.to_tree()
+    }
+}
+
+impl TryFrom<&CfgTree> for Expression {
+    type Error = ParseError;
+
+    /// Fails if one of the predicates in the tree can't be converted with
+    /// [`Expression::try_from`]
+    fn try_from(tree: &CfgTree) -> Result<Self, Self::Error> {
+        // Each child is already in canonical form, so they can be joined
+        // directly rather than going through the public constructors
+        Ok(match tree {
+            CfgTree::All(children) => Self::join(
+                Func::All(0),
+                children
+                    .iter()
+                    .map(Self::try_from)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            CfgTree::Any(children) => Self::join(
+                Func::Any(0),
+                children
+                    .iter()
+                    .map(Self::try_from)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            CfgTree::Not(child) => Self::join(Func::Not, [Self::try_from(&**child)?]),
+            CfgTree::Pred(pred) => pred.as_predicate().try_into()?,
+            CfgTree::Literal(value) => Self::literal(*value),
+        })
+    }
+}
+
+impl TryFrom<CfgTree> for Expression {
+    type Error = ParseError;
+
+    fn try_from(tree: CfgTree) -> Result<Self, Self::Error> {
+        (&tree).try_into()
+    }
+}
+
+/// Displays the tree in the same canonical form as [`Expression`]s built from it
+impl std::fmt::Display for CfgTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (name, children) = match self {
+            Self::All(children) => ("all", children.as_slice()),
+            Self::Any(children) => ("any", children.as_slice()),
+            Self::Not(child) => ("not", std::slice::from_ref(&**child)),
+            Self::Pred(pred) => return pred.fmt(f),
+            Self::Literal(value) => return value.fmt(f),
+        };
+
+        write!(f, "{name}(")?;
+        for (i, child) in children.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            child.fmt(f)?;
+        }
+        f.write_str(")")
+    }
+}
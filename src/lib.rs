@@ -7,6 +7,9 @@ pub mod expr;
 /// Types related to rustc targets
 pub mod targets;
 
+#[cfg(feature = "serde")]
+mod serde_impls;
+
 pub use error::ParseError;
 pub use expr::{Expression, Predicate, TargetPredicate};
 
@@ -0,0 +1,235 @@
+//! [`Serialize`] and [`Deserialize`] implementations for the types that can't
+//! simply derive them
+//!
+//! Expressions and predicates are serialized as their cfg syntax, as that is
+//! both the most compact and the most readable representation, and validated
+//! through the parser when deserialized.
+
+use crate::{
+    error::Reason,
+    expr::{
+        CfgTree, Dialect, Expression, FmtDebug, OwnedPredicate, ParseOptions, Predicate,
+        RelocationModel, RustcVersion, Sanitizer,
+    },
+    targets::{
+        Abi, Arch, Endian, Env, Families, Family, HasAtomic, HasAtomics, Os, Panic, Platform,
+        TargetFeature, TargetFeatures, Triple, Vendor,
+    },
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+/// Implements (de)serialization as a string via `Display` and `FromStr`
+macro_rules! as_string {
+    ($($kind:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $kind {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $kind {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                    s.parse().map_err(D::Error::custom)
+                }
+            }
+        )+
+    };
+}
+
+as_string!(
+    Platform,
+    HasAtomic,
+    Endian,
+    RustcVersion,
+    Sanitizer,
+    FmtDebug,
+    RelocationModel
+);
+
+/// The newtypes accept any string
+macro_rules! field {
+    ($($kind:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $kind {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(self.as_str())
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $kind {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    String::deserialize(deserializer).map(Self::new)
+                }
+            }
+        )+
+    };
+}
+
+field!(
+    Triple,
+    Abi,
+    Arch,
+    Vendor,
+    Os,
+    Family,
+    Env,
+    Panic,
+    TargetFeature
+);
+
+/// The sets are deserialized via their constructor, which sorts them
+macro_rules! set {
+    ($($kind:ty => $item:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $kind {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_seq(self.iter())
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $kind {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    Vec::<$item>::deserialize(deserializer).map(Self::new)
+                }
+            }
+        )+
+    };
+}
+
+set!(
+    Families => Family,
+    HasAtomics => HasAtomic,
+    TargetFeatures => TargetFeature,
+);
+
+impl Serialize for Expression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Accepts an expression that was parsed with any of the dialects. Cargo's
+/// dialect only accepts a subset of rustc's, and if an expression is valid in
+/// both the strict and rustc dialects, they parse it the same way.
+impl<'de> Deserialize<'de> for Expression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+        Expression::parse(&s)
+            .or_else(|err| {
+                let options = ParseOptions {
+                    dialect: Dialect::Rustc,
+                    ..Default::default()
+                };
+                Expression::parse_with_options(&s, options).map_err(|_rustc| err)
+            })
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for Predicate<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for OwnedPredicate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OwnedPredicate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let expr = Expression::deserialize(deserializer)?;
+
+        match expr.to_tree() {
+            CfgTree::Pred(pred) => Ok(pred),
+            _ => Err(D::Error::custom(format_args!(
+                "expected a single predicate, found '{expr}'"
+            ))),
+        }
+    }
+}
+
+/// The lists of expected terms that [`Reason::Unexpected`] is created with
+/// in this crate, other than the values of the enums, which are taken from
+/// their `FromStr` errors
+const EXPECTED: &[&[&str]] = &[
+    // Expressions
+    &[],
+    &["("],
+    &[")"],
+    &[")", ","],
+    &[")", "<key>"],
+    &[",", ")"],
+    &["=", ",", ")"],
+    &["\""],
+    &["\"<version>\""],
+    &["\"<value>\""],
+    &["<feature>"],
+    &["<key>", "all", "any", "not"],
+    &["<key>", ")", "all", "any", "not"],
+    &["= \"<feature_name>\""],
+    &["= \"<panic_strategy>\""],
+    &["= \"<target_cfg_value>\""],
+    &["= \"<sanitizer>\""],
+    &["= \"<fmt_debug>\""],
+    &["= \"<relocation_model>\""],
+    &[
+        "target_abi",
+        "target_arch",
+        "target_feature",
+        "target_os",
+        "target_family",
+        "target_env",
+        "target_endian",
+        "target_has_atomic",
+        "target_has_atomic_load_store",
+        "target_has_atomic_equal_alignment",
+        "target_pointer_width",
+        "target_vendor",
+        "target_thread_local",
+        "target_has_reliable_f16",
+        "target_has_reliable_f16_math",
+        "target_has_reliable_f128",
+        "target_has_reliable_f128_math",
+    ],
+    // `--check-cfg` arguments
+    &["cfg("],
+    &["<key>", "any", "values"],
+    &["\"<value>\"", "none", "any"],
+    // `--print cfg` output
+    &["target_arch"],
+    &["target_endian"],
+    &["target_pointer_width"],
+];
+
+/// Deserializes the list of expected terms of [`Reason::Unexpected`]
+///
+/// The lists are `'static` as they are always string literals in this crate,
+/// so only the lists the crate itself creates can be deserialized.
+pub(crate) fn expected<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<&'static [&'static str], D::Error> {
+    let terms = Vec::<std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+    let values = [
+        "".parse::<Endian>().err(),
+        "".parse::<Sanitizer>().err(),
+        "".parse::<FmtDebug>().err(),
+        "".parse::<RelocationModel>().err(),
+    ];
+
+    EXPECTED
+        .iter()
+        .copied()
+        .chain(values.into_iter().filter_map(|reason| match reason {
+            Some(Reason::Unexpected(expected)) => Some(expected),
+            _ => None,
+        }))
+        .find(|list| list.iter().eq(terms.iter()))
+        .ok_or_else(|| D::Error::custom(format_args!("unknown list of expected terms {terms:?}")))
+}
@@ -2,10 +2,22 @@ use crate::error::{HasAtomicParseError, Reason};
 use std::{borrow::Cow, ops::Deref};
 
 mod builtins;
+mod cfg_set;
+#[cfg(feature = "history")]
+mod history;
+mod platform;
+mod spec;
 
 /// A list of all of the [builtin](https://doc.rust-lang.org/nightly/nightly-rustc/rustc_target/spec/index.html#modules)
 /// targets known to rustc, as of 1.54.0
 pub use builtins::ALL_BUILTINS;
+pub use cfg_set::CfgSet;
+/// The history of every builtin target over a range of rustc releases, see
+/// [`history_versions`]
+#[cfg(feature = "history")]
+pub use history::BUILTIN_HISTORY;
+pub use platform::Platform;
+pub use spec::{find_target_spec, find_target_spec_in};
 
 /// The unique identifier for a target.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -43,6 +55,10 @@ pub struct Env(pub Cow<'static, str>);
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Panic(pub Cow<'static, str>);
 
+/// A target feature, eg. `sse2`, as used by the `target_feature` predicate.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TargetFeature(pub Cow<'static, str>);
+
 macro_rules! field_impls {
     ($kind:ident) => {
         impl $kind {
@@ -90,6 +106,7 @@ field_impls!(Os);
 field_impls!(Family);
 field_impls!(Env);
 field_impls!(Panic);
+field_impls!(TargetFeature);
 
 /// Integer size and pointers for which there's support for atomic functions.
 #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -252,6 +269,68 @@ impl std::fmt::Display for HasAtomics {
     }
 }
 
+/// The set of target features that are enabled for a target.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TargetFeatures(Cow<'static, [TargetFeature]>);
+
+impl TargetFeatures {
+    /// Constructs a new instance.
+    ///
+    /// If you have a `&'static [TargetFeature]`, prefer [`Self::new_const`].
+    #[inline]
+    pub fn new(val: impl IntoIterator<Item = TargetFeature>) -> Self {
+        let mut feats: Vec<_> = val.into_iter().collect();
+        feats.sort_unstable();
+        Self(Cow::Owned(feats))
+    }
+
+    /// Constructs a new instance of this struct from a static slice of [`TargetFeature`].
+    ///
+    /// `val` must be in sorted order: this constructor cannot check for that due to
+    /// limitations in current versions of Rust. [`Self::contains`] uses a binary
+    /// search, so it may not find a feature in a set that isn't sorted.
+    #[inline]
+    pub const fn new_const(val: &'static [TargetFeature]) -> Self {
+        Self(Cow::Borrowed(val))
+    }
+
+    /// Returns true if this set of target features contains a given feature.
+    #[inline]
+    pub fn contains(&self, val: &str) -> bool {
+        self.0
+            .binary_search_by(|feat| feat.as_str().cmp(val))
+            .is_ok()
+    }
+}
+
+impl Deref for TargetFeatures {
+    type Target = [TargetFeature];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[TargetFeature]> for TargetFeatures {
+    #[inline]
+    fn as_ref(&self) -> &[TargetFeature] {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for TargetFeatures {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        let len = self.0.len();
+        for (idx, feat) in self.0.iter().enumerate() {
+            write!(f, "{feat}")?;
+            if idx + 1 < len {
+                write!(f, ", ")?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
 macro_rules! target_enum {
     (
         $(#[$outer:meta])*
@@ -279,6 +358,14 @@ macro_rules! target_enum {
                 )+
             }
         }
+
+        impl std::fmt::Display for $kind {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    $(Self::$name => stringify!($name),)+
+                })
+            }
+        }
     };
 }
 
@@ -312,8 +399,67 @@ target_enum! {
     }
 }
 
+/// The support metadata rustc records for a target, see the
+/// [platform support](https://doc.rust-lang.org/nightly/rustc/platform-support.html)
+/// documentation for what each of these means
+///
+/// Each field is `None` if rustc doesn't specify it for the target, which is
+/// also the case for every target before rustc started recording it.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetMetadata {
+    /// The support tier of the target, 1, 2, or 3
+    pub tier: Option<u8>,
+    /// Whether the target supports the standard library, rather than just
+    /// `core`, and possibly `alloc`
+    pub std: Option<bool>,
+    /// Whether the host tools, eg. `rustc` and `cargo`, are available for the
+    /// target
+    pub host_tools: Option<bool>,
+}
+
+impl TargetMetadata {
+    /// Metadata where nothing is known about the target
+    pub const UNKNOWN: Self = Self {
+        tier: None,
+        std: None,
+        host_tools: None,
+    };
+}
+
+/// The floating point types that the codegen backend reliably supports for a
+/// target, which are only known for rustc releases with the unstable
+/// `target_has_reliable_*` cfgs
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReliableFloats {
+    /// `f16` can be used without miscompilations or linker errors. Used by the
+    /// `target_has_reliable_f16` predicate.
+    pub f16: bool,
+    /// The math functions for `f16` are available. Used by the
+    /// `target_has_reliable_f16_math` predicate.
+    pub f16_math: bool,
+    /// `f128` can be used without miscompilations or linker errors. Used by
+    /// the `target_has_reliable_f128` predicate.
+    pub f128: bool,
+    /// The math functions for `f128` are available. Used by the
+    /// `target_has_reliable_f128_math` predicate.
+    pub f128_math: bool,
+}
+
+impl ReliableFloats {
+    /// Neither `f16` nor `f128` are reliably supported
+    pub const NONE: Self = Self {
+        f16: false,
+        f16_math: false,
+        f128: false,
+        f128_math: false,
+    };
+}
+
 /// Contains information regarding a particular target known to rustc
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TargetInfo {
     /// The target's unique identifier
     pub triple: Triple,
@@ -350,9 +496,53 @@ pub struct TargetInfo {
     pub endian: Endian,
     /// The target's support for atomics. Used by the `has_target_atomics` predicate.
     pub has_atomics: HasAtomics,
+    /// The atomic sizes the target can load and store, which may include
+    /// sizes without compare and swap support. Used by the
+    /// `target_has_atomic_load_store` predicate.
+    pub has_atomics_load_store: HasAtomics,
+    /// The atomic sizes that have the same alignment as the integer of that
+    /// size. Used by the `target_has_atomic_equal_alignment` predicate.
+    pub has_atomics_equal_alignment: HasAtomics,
     /// The panic strategy used on this target by default. Used by the
     /// [panic](https://doc.rust-lang.org/beta/reference/conditional-compilation.html#panic) predicate.
     pub panic: Panic,
+    /// The target features that are enabled by default for this target. Used by the
+    /// [target_feature](https://doc.rust-lang.org/reference/conditional-compilation.html#target_feature)
+    /// predicate.
+    ///
+    /// ```
+    /// use cfg_expr::{targets::get_builtin_target_by_triple, Expression, Predicate};
+    ///
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// let expr = Expression::parse(r#"all(target_arch = "x86_64", target_feature = "sse2")"#).unwrap();
+    ///
+    /// assert!(expr.eval(|pred| match pred {
+    ///     Predicate::Target(tp) => tp.matches(linux),
+    ///     Predicate::TargetFeature(feat) => linux.target_features.contains(feat),
+    ///     _ => false,
+    /// }));
+    /// ```
+    pub target_features: TargetFeatures,
+    /// Whether the target supports the `#[thread_local]` attribute. Used by
+    /// the `target_thread_local` predicate.
+    pub thread_local: bool,
+    /// The floating point types the target reliably supports. Used by the
+    /// `target_has_reliable_*` predicates.
+    pub reliable_floats: ReliableFloats,
+    /// The target's support metadata, which isn't used by any predicate
+    ///
+    /// ```
+    /// use cfg_expr::targets::get_builtin_target_by_triple;
+    ///
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// assert_eq!(linux.metadata.tier, Some(1));
+    /// assert_eq!(linux.metadata.host_tools, Some(true));
+    ///
+    /// let thumb = get_builtin_target_by_triple("thumbv7em-none-eabihf").unwrap();
+    /// assert_eq!(thumb.metadata.tier, Some(2));
+    /// assert_eq!(thumb.metadata.std, Some(false));
+    /// ```
+    pub metadata: TargetMetadata,
 }
 
 /// Attempts to find the `TargetInfo` for the specified target triple
@@ -367,6 +557,33 @@ pub fn get_builtin_target_by_triple(triple: &str) -> Option<&'static TargetInfo>
         .ok()
 }
 
+/// Iterates over each builtin target with the specified support tier
+///
+/// ```
+/// let tier1 = cfg_expr::targets::builtins_with_tier(1);
+/// assert!(tier1.into_iter().any(|ti| ti.triple.as_str() == "aarch64-apple-darwin"));
+/// ```
+pub fn builtins_with_tier(tier: u8) -> impl Iterator<Item = &'static TargetInfo> {
+    ALL_BUILTINS
+        .iter()
+        .filter(move |ti| ti.metadata.tier == Some(tier))
+}
+
+/// Iterates over each builtin target that supports the standard library
+pub fn builtins_with_std() -> impl Iterator<Item = &'static TargetInfo> {
+    ALL_BUILTINS
+        .iter()
+        .filter(|ti| ti.metadata.std == Some(true))
+}
+
+/// Iterates over each builtin target that the host tools, eg. `rustc` and
+/// `cargo`, are available for
+pub fn builtins_with_host_tools() -> impl Iterator<Item = &'static TargetInfo> {
+    ALL_BUILTINS
+        .iter()
+        .filter(|ti| ti.metadata.host_tools == Some(true))
+}
+
 /// Retrieves the version of rustc for which the built-in targets were
 /// retrieved from.
 ///
@@ -375,6 +592,129 @@ pub fn rustc_version() -> &'static str {
     builtins::RUSTC_VERSION
 }
 
+/// How a builtin target changed over the range of rustc releases recorded in
+/// [`BUILTIN_HISTORY`]
+#[cfg(feature = "history")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TargetHistory {
+    /// The target's unique identifier
+    pub triple: Triple,
+    /// Each release in which the target changed, in ascending order
+    pub changes: &'static [TargetChange],
+}
+
+/// A change to a builtin target, see [`TargetHistory`]
+#[cfg(feature = "history")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TargetChange {
+    /// The first release this change applies to
+    pub since: crate::expr::RustcVersion,
+    /// The target information as of this release, or `None` if the target
+    /// was removed
+    pub info: Option<TargetInfo>,
+}
+
+#[cfg(feature = "history")]
+impl TargetHistory {
+    /// Gets the target information for the specified rustc version
+    ///
+    /// Returns `None` if the target didn't exist in that release, or the
+    /// release is older than the [recorded history](history_versions). Newer
+    /// releases use the most recent information.
+    pub fn get(&self, version: &crate::expr::RustcVersion) -> Option<&'static TargetInfo> {
+        if *version < history::FIRST_VERSION {
+            return None;
+        }
+
+        let changes = self.changes;
+        let index = changes.partition_point(|change| change.since <= *version);
+        index
+            .checked_sub(1)
+            .and_then(|index| changes[index].info.as_ref())
+    }
+
+    /// The first recorded release in which the target existed
+    ///
+    /// Targets that already existed in the first release of the
+    /// [recorded history](history_versions) may be older than that.
+    pub fn first_version(&self) -> crate::expr::RustcVersion {
+        self.changes
+            .iter()
+            .find(|change| change.info.is_some())
+            .map_or(history::FIRST_VERSION, |change| change.since)
+    }
+
+    /// The last recorded release in which the target existed
+    ///
+    /// If this is the end of the [recorded history](history_versions), the
+    /// target is still builtin.
+    pub fn last_version(&self) -> crate::expr::RustcVersion {
+        match self.changes.last() {
+            // Every minor release is recorded, so the target was last
+            // available in the release before it was removed
+            Some(TargetChange { since, info: None }) => match since.minor.checked_sub(1) {
+                Some(minor) => crate::expr::RustcVersion { minor, ..*since },
+                // The last release of the previous major version isn't known,
+                // so fall back to the last change while the target existed
+                None => self
+                    .changes
+                    .iter()
+                    .rev()
+                    .find(|change| change.info.is_some())
+                    .map_or(history::FIRST_VERSION, |change| change.since),
+            },
+            _ => history::LAST_VERSION,
+        }
+    }
+}
+
+/// The range of rustc releases recorded in [`BUILTIN_HISTORY`]
+///
+/// ```
+/// let versions = cfg_expr::targets::history_versions();
+/// assert_eq!(versions.start().to_string(), "1.60.0");
+/// assert_eq!(versions.end().to_string(), cfg_expr::targets::rustc_version());
+/// ```
+#[cfg(feature = "history")]
+pub fn history_versions() -> std::ops::RangeInclusive<crate::expr::RustcVersion> {
+    history::FIRST_VERSION..=history::LAST_VERSION
+}
+
+/// Attempts to find the [`TargetHistory`] for the specified target triple,
+/// including targets that are no longer builtin
+#[cfg(feature = "history")]
+pub fn get_target_history(triple: &str) -> Option<&'static TargetHistory> {
+    BUILTIN_HISTORY
+        .binary_search_by(|th| th.triple.as_ref().cmp(triple))
+        .map(|i| &BUILTIN_HISTORY[i])
+        .ok()
+}
+
+/// Attempts to find the `TargetInfo` for the specified target triple, as it
+/// was in the specified rustc release, see [`TargetHistory::get`]
+///
+/// ```
+/// use cfg_expr::targets::{get_target_for_version, Abi, Env};
+///
+/// let catalyst = |version: &str| {
+///     get_target_for_version("x86_64-apple-ios-macabi", &version.parse().unwrap()).unwrap()
+/// };
+///
+/// // Mac Catalyst gained `target_abi = "macabi"` in 1.78, and later
+/// // `target_env = "macabi"` as well
+/// assert_eq!(catalyst("1.75").abi, None);
+/// assert_eq!(catalyst("1.80").abi, Some(Abi::new("macabi")));
+/// assert_eq!(catalyst("1.80").env, None);
+/// assert_eq!(catalyst("1.91").env, Some(Env::new("macabi")));
+/// ```
+#[cfg(feature = "history")]
+pub fn get_target_for_version(
+    triple: &str,
+    version: &crate::expr::RustcVersion,
+) -> Option<&'static TargetInfo> {
+    get_target_history(triple)?.get(version)
+}
+
 #[cfg(test)]
 mod test {
     use crate::targets::get_builtin_target_by_triple;
@@ -24,7 +24,55 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dit,
+                    TargetFeature::dotprod,
+                    TargetFeature::dpb,
+                    TargetFeature::dpb2,
+                    TargetFeature::fcma,
+                    TargetFeature::fhm,
+                    TargetFeature::flagm,
+                    TargetFeature::fp16,
+                    TargetFeature::frintts,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rcpc2,
+                    TargetFeature::rdm,
+                    TargetFeature::sb,
+                    TargetFeature::sha2,
+                    TargetFeature::sha3,
+                    TargetFeature::ssbs,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(1),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-ios"),
@@ -37,7 +85,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::neon,
+                    TargetFeature::pmuv3,
+                    TargetFeature::sha2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-ios-macabi"),
@@ -50,7 +122,45 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dpb,
+                    TargetFeature::fcma,
+                    TargetFeature::fp16,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rdm,
+                    TargetFeature::sha2,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-ios-sim"),
@@ -63,7 +173,45 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dpb,
+                    TargetFeature::fcma,
+                    TargetFeature::fp16,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rdm,
+                    TargetFeature::sha2,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-tvos"),
@@ -76,7 +224,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::neon,
+                    TargetFeature::pmuv3,
+                    TargetFeature::sha2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-tvos-sim"),
@@ -89,7 +261,45 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dpb,
+                    TargetFeature::fcma,
+                    TargetFeature::fp16,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rdm,
+                    TargetFeature::sha2,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-visionos"),
@@ -102,7 +312,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::neon,
+                    TargetFeature::pmuv3,
+                    TargetFeature::sha2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-visionos-sim"),
@@ -115,7 +349,45 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dpb,
+                    TargetFeature::fcma,
+                    TargetFeature::fp16,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rdm,
+                    TargetFeature::sha2,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-watchos"),
@@ -128,7 +400,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::neon,
+                    TargetFeature::pmuv3,
+                    TargetFeature::sha2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-watchos-sim"),
@@ -141,7 +437,45 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dpb,
+                    TargetFeature::fcma,
+                    TargetFeature::fp16,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rdm,
+                    TargetFeature::sha2,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-kmc-solid_asp3"),
@@ -154,7 +488,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-linux-android"),
@@ -167,7 +516,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-nintendo-switch-freestanding"),
@@ -180,7 +544,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::neon,
+                    TargetFeature::sha2,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-pc-windows-gnullvm"),
@@ -193,7 +581,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-pc-windows-msvc"),
@@ -206,7 +609,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(1),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-freebsd"),
@@ -219,7 +637,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-fuchsia"),
@@ -232,7 +665,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::neon,
+                    TargetFeature::sha2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-helenos"),
@@ -245,7 +702,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-hermit"),
@@ -258,7 +730,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-illumos"),
@@ -271,7 +758,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-linux-gnu"),
@@ -284,7 +786,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(1),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-linux-gnu_ilp32"),
@@ -297,7 +814,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-linux-musl"),
@@ -310,7 +842,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::crt_static, TargetFeature::neon] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-linux-ohos"),
@@ -323,7 +872,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-managarm-mlibc"),
@@ -336,7 +900,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-netbsd"),
@@ -349,7 +928,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-none"),
@@ -362,7 +956,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-none-softfloat"),
@@ -375,7 +984,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-nto-qnx700"),
@@ -388,7 +1012,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-nto-qnx710"),
@@ -401,7 +1040,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-nto-qnx710_iosock"),
@@ -414,7 +1068,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-nto-qnx800"),
@@ -427,7 +1096,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-nuttx"),
@@ -440,7 +1124,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-openbsd"),
@@ -453,7 +1152,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-redox"),
@@ -466,7 +1180,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::crt_static, TargetFeature::neon] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-teeos"),
@@ -479,7 +1210,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-trusty"),
@@ -492,7 +1238,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::crt_static, TargetFeature::neon] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-uefi"),
@@ -505,7 +1268,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-uwp-windows-msvc"),
@@ -518,7 +1296,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-wrs-vxworks"),
@@ -531,7 +1324,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::crt_static, TargetFeature::neon] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64_be-unknown-hermit"),
@@ -544,7 +1354,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64_be-unknown-linux-gnu"),
@@ -557,7 +1382,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64_be-unknown-linux-gnu_ilp32"),
@@ -570,7 +1410,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64_be-unknown-linux-musl"),
@@ -583,7 +1438,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64_be-unknown-netbsd"),
@@ -596,7 +1466,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64_be-unknown-none-softfloat"),
@@ -609,7 +1494,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64v8r-unknown-none"),
@@ -622,7 +1522,39 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crc,
+                    TargetFeature::dit,
+                    TargetFeature::dpb,
+                    TargetFeature::flagm,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rcpc2,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("aarch64v8r-unknown-none-softfloat"),
@@ -635,7 +1567,38 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crc,
+                    TargetFeature::dit,
+                    TargetFeature::dpb,
+                    TargetFeature::flagm,
+                    TargetFeature::lse,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rcpc2,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("amdgcn-amd-amdhsa"),
@@ -648,7 +1611,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm-linux-androideabi"),
@@ -661,7 +1639,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm-unknown-linux-gnueabi"),
@@ -674,7 +1667,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm-unknown-linux-gnueabihf"),
@@ -687,7 +1695,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm-unknown-linux-musleabi"),
@@ -700,7 +1723,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm-unknown-linux-musleabihf"),
@@ -713,7 +1751,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm64_32-apple-watchos"),
@@ -726,7 +1779,45 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dpb,
+                    TargetFeature::fcma,
+                    TargetFeature::fp16,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rdm,
+                    TargetFeature::sha2,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm64e-apple-darwin"),
@@ -739,7 +1830,55 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dit,
+                    TargetFeature::dotprod,
+                    TargetFeature::dpb,
+                    TargetFeature::dpb2,
+                    TargetFeature::fcma,
+                    TargetFeature::fhm,
+                    TargetFeature::flagm,
+                    TargetFeature::fp16,
+                    TargetFeature::frintts,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rcpc2,
+                    TargetFeature::rdm,
+                    TargetFeature::sb,
+                    TargetFeature::sha2,
+                    TargetFeature::sha3,
+                    TargetFeature::ssbs,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm64e-apple-ios"),
@@ -752,7 +1891,45 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dpb,
+                    TargetFeature::fcma,
+                    TargetFeature::fp16,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rdm,
+                    TargetFeature::sha2,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm64e-apple-tvos"),
@@ -765,7 +1942,45 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::aes,
+                    TargetFeature::crc,
+                    TargetFeature::dpb,
+                    TargetFeature::fcma,
+                    TargetFeature::fp16,
+                    TargetFeature::jsconv,
+                    TargetFeature::lor,
+                    TargetFeature::lse,
+                    TargetFeature::neon,
+                    TargetFeature::paca,
+                    TargetFeature::pacg,
+                    TargetFeature::pan,
+                    TargetFeature::pmuv3,
+                    TargetFeature::ras,
+                    TargetFeature::rcpc,
+                    TargetFeature::rdm,
+                    TargetFeature::sha2,
+                    TargetFeature::vh,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("arm64ec-pc-windows-msvc"),
@@ -778,7 +1993,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::neon] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armeb-unknown-linux-gnueabi"),
@@ -791,7 +2021,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armebv7r-none-eabi"),
@@ -804,7 +2049,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armebv7r-none-eabihf"),
@@ -817,7 +2077,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv4t-none-eabi"),
@@ -830,7 +2105,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv4t-unknown-linux-gnueabi"),
@@ -843,7 +2133,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv5te-none-eabi"),
@@ -856,7 +2161,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv5te-unknown-linux-gnueabi"),
@@ -869,7 +2189,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv5te-unknown-linux-musleabi"),
@@ -882,7 +2217,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv5te-unknown-linux-uclibceabi"),
@@ -895,7 +2245,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv6-none-eabi"),
@@ -908,7 +2273,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv6-none-eabihf"),
@@ -921,7 +2301,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv6-unknown-freebsd"),
@@ -934,7 +2329,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv6-unknown-netbsd-eabihf"),
@@ -947,7 +2357,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv6k-nintendo-3ds"),
@@ -960,7 +2385,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-linux-androideabi"),
@@ -973,7 +2413,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-rtems-eabihf"),
@@ -986,7 +2441,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-sony-vita-newlibeabihf"),
@@ -999,7 +2469,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-freebsd"),
@@ -1012,7 +2497,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-gnueabi"),
@@ -1025,7 +2525,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-gnueabihf"),
@@ -1038,7 +2553,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-musleabi"),
@@ -1051,7 +2581,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-musleabihf"),
@@ -1064,7 +2609,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-ohos"),
@@ -1077,7 +2637,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-uclibceabi"),
@@ -1090,7 +2665,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-uclibceabihf"),
@@ -1103,7 +2693,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-netbsd-eabihf"),
@@ -1116,7 +2721,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-trusty"),
@@ -1129,7 +2749,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7-wrs-vxworks-eabihf"),
@@ -1142,7 +2777,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-kmc-solid_asp3-eabi"),
@@ -1155,7 +2805,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-kmc-solid_asp3-eabihf"),
@@ -1168,7 +2833,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-none-eabi"),
@@ -1181,7 +2861,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-none-eabihf"),
@@ -1194,7 +2889,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-nuttx-eabi"),
@@ -1207,7 +2917,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-nuttx-eabihf"),
@@ -1220,7 +2945,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-vex-v5"),
@@ -1233,7 +2973,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7k-apple-watchos"),
@@ -1246,7 +3001,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7r-none-eabi"),
@@ -1259,7 +3029,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7r-none-eabihf"),
@@ -1272,7 +3057,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv7s-apple-ios"),
@@ -1285,7 +3085,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("armv8r-none-eabihf"),
@@ -1298,7 +3113,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("avr-none"),
@@ -1311,7 +3141,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 16,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("bpfeb-unknown-none"),
@@ -1324,7 +3169,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("bpfel-unknown-none"),
@@ -1337,7 +3197,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("csky-unknown-linux-gnuabiv2"),
@@ -1350,7 +3225,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("csky-unknown-linux-gnuabiv2hf"),
@@ -1363,7 +3253,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("hexagon-unknown-linux-musl"),
@@ -1376,7 +3281,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("hexagon-unknown-none-elf"),
@@ -1389,7 +3309,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("hexagon-unknown-qurt"),
@@ -1402,7 +3337,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i386-apple-ios"),
@@ -1415,7 +3365,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i586-unknown-linux-gnu"),
@@ -1428,7 +3405,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i586-unknown-linux-musl"),
@@ -1441,7 +3433,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i586-unknown-netbsd"),
@@ -1454,7 +3461,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i586-unknown-redox"),
@@ -1467,7 +3489,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-apple-darwin"),
@@ -1480,7 +3517,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-linux-android"),
@@ -1493,7 +3557,32 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-pc-nto-qnx700"),
@@ -1506,7 +3595,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-pc-windows-gnu"),
@@ -1519,7 +3625,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-pc-windows-gnullvm"),
@@ -1532,7 +3655,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-pc-windows-msvc"),
@@ -1545,7 +3685,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(1),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-freebsd"),
@@ -1558,7 +3715,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-haiku"),
@@ -1571,7 +3745,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-helenos"),
@@ -1584,7 +3775,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-hurd-gnu"),
@@ -1597,7 +3805,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-linux-gnu"),
@@ -1610,7 +3835,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(1),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-linux-musl"),
@@ -1623,7 +3865,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crt_static,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-netbsd"),
@@ -1636,7 +3902,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-openbsd"),
@@ -1649,7 +3932,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-uefi"),
@@ -1662,7 +3962,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::fxsr] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-uwp-windows-gnu"),
@@ -1675,7 +3990,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-uwp-windows-msvc"),
@@ -1688,7 +4020,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-win7-windows-gnu"),
@@ -1701,7 +4050,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-win7-windows-msvc"),
@@ -1714,7 +4080,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("i686-wrs-vxworks"),
@@ -1727,7 +4110,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crt_static,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("loongarch32-unknown-none"),
@@ -1740,7 +4147,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::d, TargetFeature::f] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("loongarch32-unknown-none-softfloat"),
@@ -1753,7 +4175,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("loongarch64-unknown-linux-gnu"),
@@ -1766,7 +4203,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::d, TargetFeature::f, TargetFeature::lsx] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("loongarch64-unknown-linux-musl"),
@@ -1779,7 +4233,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::d, TargetFeature::f, TargetFeature::lsx] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("loongarch64-unknown-linux-ohos"),
@@ -1792,7 +4263,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::d, TargetFeature::f, TargetFeature::lsx] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("loongarch64-unknown-none"),
@@ -1805,7 +4293,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::d, TargetFeature::f] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("loongarch64-unknown-none-softfloat"),
@@ -1818,7 +4321,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("m68k-unknown-linux-gnu"),
@@ -1831,7 +4349,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("m68k-unknown-none-elf"),
@@ -1844,7 +4377,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips-mti-none-elf"),
@@ -1857,7 +4405,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips-unknown-linux-gnu"),
@@ -1870,7 +4433,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips-unknown-linux-musl"),
@@ -1883,7 +4461,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips-unknown-linux-uclibc"),
@@ -1896,7 +4489,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips64-openwrt-linux-musl"),
@@ -1909,7 +4517,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips64-unknown-linux-gnuabi64"),
@@ -1922,7 +4545,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips64-unknown-linux-muslabi64"),
@@ -1935,7 +4573,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips64el-unknown-linux-gnuabi64"),
@@ -1948,7 +4601,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mips64el-unknown-linux-muslabi64"),
@@ -1961,7 +4629,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-mti-none-elf"),
@@ -1974,7 +4657,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-sony-psp"),
@@ -1987,7 +4685,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-sony-psx"),
@@ -2000,7 +4713,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-unknown-linux-gnu"),
@@ -2013,7 +4741,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-unknown-linux-musl"),
@@ -2026,7 +4769,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-unknown-linux-uclibc"),
@@ -2039,7 +4797,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-unknown-netbsd"),
@@ -2052,7 +4825,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-unknown-none"),
@@ -2065,7 +4853,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsisa32r6-unknown-linux-gnu"),
@@ -2078,7 +4881,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsisa32r6el-unknown-linux-gnu"),
@@ -2091,7 +4909,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsisa64r6-unknown-linux-gnuabi64"),
@@ -2104,7 +4937,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("mipsisa64r6el-unknown-linux-gnuabi64"),
@@ -2117,7 +4965,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("msp430-none-elf"),
@@ -2130,7 +4993,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 16,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("nvptx64-nvidia-cuda"),
@@ -2143,7 +5021,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-freebsd"),
@@ -2156,7 +5049,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-helenos"),
@@ -2169,7 +5077,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-linux-gnu"),
@@ -2182,7 +5105,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-linux-gnuspe"),
@@ -2195,7 +5133,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-linux-musl"),
@@ -2208,7 +5161,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-linux-muslspe"),
@@ -2221,7 +5189,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-netbsd"),
@@ -2234,7 +5217,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-openbsd"),
@@ -2247,7 +5245,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-wrs-vxworks"),
@@ -2260,7 +5273,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-wrs-vxworks-spe"),
@@ -2273,7 +5301,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-ibm-aix"),
@@ -2286,7 +5329,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-unknown-freebsd"),
@@ -2299,7 +5357,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-unknown-linux-gnu"),
@@ -2312,7 +5385,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-unknown-linux-musl"),
@@ -2325,7 +5413,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-unknown-openbsd"),
@@ -2338,7 +5441,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-wrs-vxworks"),
@@ -2351,7 +5469,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64le-unknown-freebsd"),
@@ -2364,7 +5497,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64le-unknown-linux-gnu"),
@@ -2377,7 +5525,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64le-unknown-linux-musl"),
@@ -2390,7 +5553,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::crt_static] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32-wrs-vxworks"),
@@ -2403,7 +5581,36 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::crt_static,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32e-unknown-none-elf"),
@@ -2416,7 +5623,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32em-unknown-none-elf"),
@@ -2429,7 +5651,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::m] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32emc-unknown-none-elf"),
@@ -2442,7 +5679,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::c, TargetFeature::m, TargetFeature::zca] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32gc-unknown-linux-gnu"),
@@ -2455,7 +5709,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32gc-unknown-linux-musl"),
@@ -2468,7 +5750,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32i-unknown-none-elf"),
@@ -2481,7 +5791,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32im-risc0-zkvm-elf"),
@@ -2494,7 +5819,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::m] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32im-unknown-none-elf"),
@@ -2507,7 +5847,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::m] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32ima-unknown-none-elf"),
@@ -2520,7 +5875,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imac-esp-espidf"),
@@ -2533,7 +5912,33 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imac-unknown-none-elf"),
@@ -2546,7 +5951,33 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imac-unknown-nuttx-elf"),
@@ -2559,7 +5990,33 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imac-unknown-xous-elf"),
@@ -2572,7 +6029,33 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imafc-esp-espidf"),
@@ -2585,7 +6068,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imafc-unknown-none-elf"),
@@ -2598,7 +6108,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imafc-unknown-nuttx-elf"),
@@ -2611,7 +6148,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imc-esp-espidf"),
@@ -2624,7 +6188,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::c, TargetFeature::m, TargetFeature::zca] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imc-unknown-none-elf"),
@@ -2637,7 +6218,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::c, TargetFeature::m, TargetFeature::zca] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv32imc-unknown-nuttx-elf"),
@@ -2650,7 +6248,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::c, TargetFeature::m, TargetFeature::zca] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64-linux-android"),
@@ -2663,7 +6278,39 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::b,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zba,
+                    TargetFeature::zbb,
+                    TargetFeature::zbs,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64-wrs-vxworks"),
@@ -2676,7 +6323,36 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::crt_static,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64a23-unknown-linux-gnu"),
@@ -2689,7 +6365,58 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::b,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::za128rs,
+                    TargetFeature::za64rs,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zawrs,
+                    TargetFeature::zba,
+                    TargetFeature::zbb,
+                    TargetFeature::zbs,
+                    TargetFeature::zca,
+                    TargetFeature::zcb,
+                    TargetFeature::zcmop,
+                    TargetFeature::zic64b,
+                    TargetFeature::zicbom,
+                    TargetFeature::zicbop,
+                    TargetFeature::zicboz,
+                    TargetFeature::ziccamoa,
+                    TargetFeature::ziccif,
+                    TargetFeature::zicclsm,
+                    TargetFeature::ziccrse,
+                    TargetFeature::zicntr,
+                    TargetFeature::zicond,
+                    TargetFeature::zicsr,
+                    TargetFeature::zihintntl,
+                    TargetFeature::zihintpause,
+                    TargetFeature::zihpm,
+                    TargetFeature::zimop,
+                    TargetFeature::zkt,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-freebsd"),
@@ -2702,7 +6429,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-fuchsia"),
@@ -2715,7 +6470,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-hermit"),
@@ -2728,7 +6511,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-linux-gnu"),
@@ -2741,7 +6552,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-linux-musl"),
@@ -2754,7 +6593,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-managarm-mlibc"),
@@ -2767,7 +6634,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-netbsd"),
@@ -2780,7 +6674,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-none-elf"),
@@ -2793,7 +6715,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-nuttx-elf"),
@@ -2806,7 +6756,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-openbsd"),
@@ -2819,7 +6797,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                    TargetFeature::zifencei,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-redox"),
@@ -2832,7 +6838,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::crt_static,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                    TargetFeature::zicsr,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64im-unknown-none-elf"),
@@ -2845,7 +6879,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::m] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64imac-unknown-none-elf"),
@@ -2858,7 +6907,33 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("riscv64imac-unknown-nuttx-elf"),
@@ -2871,7 +6946,33 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::a,
+                    TargetFeature::c,
+                    TargetFeature::m,
+                    TargetFeature::zaamo,
+                    TargetFeature::zalrsc,
+                    TargetFeature::zca,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("s390x-unknown-linux-gnu"),
@@ -2884,7 +6985,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("s390x-unknown-linux-musl"),
@@ -2897,7 +7013,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("s390x-unknown-none-softfloat"),
@@ -2910,7 +7041,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("sparc-unknown-linux-gnu"),
@@ -2923,7 +7069,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("sparc-unknown-none-elf"),
@@ -2936,7 +7097,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: false,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("sparc64-unknown-helenos"),
@@ -2949,7 +7125,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("sparc64-unknown-linux-gnu"),
@@ -2962,7 +7153,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: true,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("sparc64-unknown-netbsd"),
@@ -2975,7 +7181,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("sparc64-unknown-openbsd"),
@@ -2988,7 +7209,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("sparcv9-sun-solaris"),
@@ -3001,7 +7237,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv4t-none-eabi"),
@@ -3014,7 +7265,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv5te-none-eabi"),
@@ -3027,7 +7293,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv6-none-eabi"),
@@ -3040,7 +7321,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv6m-none-eabi"),
@@ -3053,7 +7349,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv6m-nuttx-eabi"),
@@ -3066,7 +7377,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7a-none-eabi"),
@@ -3079,7 +7405,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7a-none-eabihf"),
@@ -3092,7 +7433,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7a-nuttx-eabi"),
@@ -3105,7 +7461,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7a-nuttx-eabihf"),
@@ -3118,7 +7489,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7a-pc-windows-msvc"),
@@ -3131,7 +7517,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7a-uwp-windows-msvc"),
@@ -3144,7 +7545,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7em-none-eabi"),
@@ -3157,7 +7573,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7em-none-eabihf"),
@@ -3170,7 +7601,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7em-nuttx-eabi"),
@@ -3183,7 +7629,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7em-nuttx-eabihf"),
@@ -3196,7 +7657,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7m-none-eabi"),
@@ -3209,7 +7685,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7m-nuttx-eabi"),
@@ -3222,7 +7713,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7neon-linux-androideabi"),
@@ -3235,7 +7741,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7neon-unknown-linux-gnueabihf"),
@@ -3248,7 +7769,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7neon-unknown-linux-musleabihf"),
@@ -3261,7 +7797,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7r-none-eabi"),
@@ -3274,7 +7825,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7r-none-eabihf"),
@@ -3287,7 +7853,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.base-none-eabi"),
@@ -3300,7 +7881,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.base-nuttx-eabi"),
@@ -3313,7 +7909,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.main-none-eabi"),
@@ -3326,7 +7937,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.main-none-eabihf"),
@@ -3339,7 +7965,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.main-nuttx-eabi"),
@@ -3352,7 +7993,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.main-nuttx-eabihf"),
@@ -3365,7 +8021,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8r-none-eabihf"),
@@ -3378,7 +8049,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm32-unknown-emscripten"),
@@ -3391,7 +8077,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::bulk_memory,
+                    TargetFeature::crt_static,
+                    TargetFeature::multivalue,
+                    TargetFeature::mutable_globals,
+                    TargetFeature::nontrapping_fptoint,
+                    TargetFeature::reference_types,
+                    TargetFeature::sign_ext,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm32-unknown-unknown"),
@@ -3404,7 +8117,33 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::bulk_memory,
+                    TargetFeature::multivalue,
+                    TargetFeature::mutable_globals,
+                    TargetFeature::nontrapping_fptoint,
+                    TargetFeature::reference_types,
+                    TargetFeature::sign_ext,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm32-wali-linux-musl"),
@@ -3417,7 +8156,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::bulk_memory,
+                    TargetFeature::crt_static,
+                    TargetFeature::multivalue,
+                    TargetFeature::mutable_globals,
+                    TargetFeature::nontrapping_fptoint,
+                    TargetFeature::reference_types,
+                    TargetFeature::sign_ext,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm32-wasip1"),
@@ -3430,7 +8196,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::bulk_memory,
+                    TargetFeature::crt_static,
+                    TargetFeature::multivalue,
+                    TargetFeature::mutable_globals,
+                    TargetFeature::nontrapping_fptoint,
+                    TargetFeature::reference_types,
+                    TargetFeature::sign_ext,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm32-wasip1-threads"),
@@ -3443,7 +8236,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::bulk_memory,
+                    TargetFeature::crt_static,
+                    TargetFeature::multivalue,
+                    TargetFeature::mutable_globals,
+                    TargetFeature::nontrapping_fptoint,
+                    TargetFeature::reference_types,
+                    TargetFeature::sign_ext,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm32-wasip2"),
@@ -3456,7 +8276,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::bulk_memory,
+                    TargetFeature::crt_static,
+                    TargetFeature::multivalue,
+                    TargetFeature::mutable_globals,
+                    TargetFeature::nontrapping_fptoint,
+                    TargetFeature::reference_types,
+                    TargetFeature::sign_ext,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm32-wasip3"),
@@ -3469,7 +8316,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::bulk_memory,
+                    TargetFeature::crt_static,
+                    TargetFeature::multivalue,
+                    TargetFeature::mutable_globals,
+                    TargetFeature::nontrapping_fptoint,
+                    TargetFeature::reference_types,
+                    TargetFeature::sign_ext,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm32v1-none"),
@@ -3482,7 +8356,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::mutable_globals] }),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("wasm64-unknown-unknown"),
@@ -3495,7 +8384,33 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::bulk_memory,
+                    TargetFeature::multivalue,
+                    TargetFeature::mutable_globals,
+                    TargetFeature::nontrapping_fptoint,
+                    TargetFeature::reference_types,
+                    TargetFeature::sign_ext,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-darwin"),
@@ -3508,7 +8423,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-ios"),
@@ -3521,7 +8463,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-ios-macabi"),
@@ -3534,7 +8503,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-tvos"),
@@ -3547,7 +8543,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-watchos-sim"),
@@ -3560,7 +8583,34 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-fortanix-unknown-sgx"),
@@ -3573,7 +8623,32 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::fxsr,
+                    TargetFeature::rdrand,
+                    TargetFeature::rdseed,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-linux-android"),
@@ -3586,7 +8661,35 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::fxsr,
+                    TargetFeature::popcnt,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::sse4_2,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-lynx-lynxos178"),
@@ -3599,7 +8702,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-cygwin"),
@@ -3612,7 +8732,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-nto-qnx710"),
@@ -3625,7 +8762,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-nto-qnx710_iosock"),
@@ -3638,7 +8792,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-nto-qnx800"),
@@ -3651,7 +8822,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-solaris"),
@@ -3664,7 +8852,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-windows-gnu"),
@@ -3677,7 +8882,27 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats::NONE,
+        metadata: TargetMetadata {
+            tier: Some(1),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-windows-gnullvm"),
@@ -3690,7 +8915,32 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-windows-msvc"),
@@ -3703,7 +8953,32 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(1),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unikraft-linux-musl"),
@@ -3716,7 +8991,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-dragonfly"),
@@ -3729,7 +9021,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-freebsd"),
@@ -3742,7 +9051,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-fuchsia"),
@@ -3755,7 +9081,36 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::popcnt,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::sse4_2,
+                    TargetFeature::ssse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-haiku"),
@@ -3768,7 +9123,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-helenos"),
@@ -3781,7 +9153,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-hermit"),
@@ -3794,7 +9183,32 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::fxsr,
+                    TargetFeature::rdrand,
+                    TargetFeature::rdseed,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-hurd-gnu"),
@@ -3807,7 +9221,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-illumos"),
@@ -3820,7 +9251,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-l4re-uclibc"),
@@ -3833,7 +9281,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-linux-gnu"),
@@ -3846,7 +9311,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(1),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-linux-gnuasan"),
@@ -3859,7 +9341,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-linux-gnumsan"),
@@ -3872,7 +9371,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-linux-gnutsan"),
@@ -3885,7 +9401,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-linux-gnux32"),
@@ -3898,7 +9431,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-linux-musl"),
@@ -3911,7 +9461,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crt_static,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-linux-none"),
@@ -3924,7 +9498,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-linux-ohos"),
@@ -3937,7 +9528,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-managarm-mlibc"),
@@ -3950,7 +9558,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-motor"),
@@ -3963,7 +9588,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crt_static,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-netbsd"),
@@ -3976,7 +9625,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-none"),
@@ -3989,7 +9655,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::fxsr] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-openbsd"),
@@ -4002,7 +9683,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-redox"),
@@ -4015,7 +9713,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crt_static,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-trusty"),
@@ -4028,7 +9750,30 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crt_static,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-uefi"),
@@ -4041,7 +9786,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[TargetFeature::fxsr] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(2),
+            std: None,
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-uwp-windows-gnu"),
@@ -4054,7 +9814,27 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                ]
+            },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats::NONE,
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-uwp-windows-msvc"),
@@ -4067,7 +9847,32 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-win7-windows-gnu"),
@@ -4080,7 +9885,19 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: false,
+        reliable_floats: ReliableFloats::NONE,
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-win7-windows-msvc"),
@@ -4093,7 +9910,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const { &[TargetFeature::fxsr, TargetFeature::sse, TargetFeature::sse2] },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-wrs-vxworks"),
@@ -4106,7 +9940,31 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::crt_static,
+                    TargetFeature::fxsr,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("x86_64h-apple-darwin"),
@@ -4119,7 +9977,46 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+        target_features: TargetFeatures::new_const(
+            const {
+                &[
+                    TargetFeature::avx,
+                    TargetFeature::avx2,
+                    TargetFeature::bmi1,
+                    TargetFeature::bmi2,
+                    TargetFeature::cmpxchg16b,
+                    TargetFeature::f16c,
+                    TargetFeature::fma,
+                    TargetFeature::fxsr,
+                    TargetFeature::lzcnt,
+                    TargetFeature::movbe,
+                    TargetFeature::popcnt,
+                    TargetFeature::sse,
+                    TargetFeature::sse2,
+                    TargetFeature::sse3,
+                    TargetFeature::sse4_1,
+                    TargetFeature::sse4_2,
+                    TargetFeature::ssse3,
+                    TargetFeature::xsave,
+                    TargetFeature::xsaveopt,
+                ]
+            },
+        ),
+        thread_local: true,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(true),
+            host_tools: Some(true),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("xtensa-esp32-espidf"),
@@ -4132,7 +10029,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("xtensa-esp32-none-elf"),
@@ -4145,7 +10057,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("xtensa-esp32s2-espidf"),
@@ -4158,7 +10085,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("xtensa-esp32s2-none-elf"),
@@ -4171,7 +10113,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
     TargetInfo {
         triple: Triple::new_const("xtensa-esp32s3-espidf"),
@@ -4184,7 +10141,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: None,
+            host_tools: None,
+        },
     },
     TargetInfo {
         triple: Triple::new_const("xtensa-esp32s3-none-elf"),
@@ -4197,7 +10169,22 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
+        target_features: TargetFeatures::new_const(const { &[] }),
+        thread_local: false,
+        reliable_floats: ReliableFloats {
+            f16: true,
+            f16_math: true,
+            f128: true,
+            f128_math: false,
+        },
+        metadata: TargetMetadata {
+            tier: Some(3),
+            std: Some(false),
+            host_tools: Some(false),
+        },
     },
 ];
 
@@ -4368,6 +10355,8 @@ impl super::Env {
     pub const v5: Env = Env::new_const("v5");
 }
 
+const __has_atomics_8: &[HasAtomic] = &[HasAtomic::IntegerSize(8)];
+const __has_atomics_8_16: &[HasAtomic] = &[HasAtomic::IntegerSize(8), HasAtomic::IntegerSize(16)];
 const __has_atomics_8_16_32_64_128_ptr: &[HasAtomic] = &[
     HasAtomic::IntegerSize(8),
     HasAtomic::IntegerSize(16),
@@ -4389,16 +10378,120 @@ const __has_atomics_8_16_32_ptr: &[HasAtomic] = &[
     HasAtomic::IntegerSize(32),
     HasAtomic::Pointer,
 ];
+const __has_atomics_8_16_ptr: &[HasAtomic] = &[
+    HasAtomic::IntegerSize(8),
+    HasAtomic::IntegerSize(16),
+    HasAtomic::Pointer,
+];
+const __has_atomics_64_ptr: &[HasAtomic] = &[HasAtomic::IntegerSize(64), HasAtomic::Pointer];
 
 impl super::HasAtomics {
+    pub const atomic_8: HasAtomics = HasAtomics::new_const(__has_atomics_8);
+    pub const atomic_8_16: HasAtomics = HasAtomics::new_const(__has_atomics_8_16);
     pub const atomic_8_16_32_64_128_ptr: HasAtomics =
         HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr);
     pub const atomic_8_16_32_64_ptr: HasAtomics =
         HasAtomics::new_const(__has_atomics_8_16_32_64_ptr);
     pub const atomic_8_16_32_ptr: HasAtomics = HasAtomics::new_const(__has_atomics_8_16_32_ptr);
+    pub const atomic_8_16_ptr: HasAtomics = HasAtomics::new_const(__has_atomics_8_16_ptr);
+    pub const atomic_64_ptr: HasAtomics = HasAtomics::new_const(__has_atomics_64_ptr);
 }
 
 impl super::Panic {
     pub const abort: Panic = Panic::new_const("abort");
     pub const unwind: Panic = Panic::new_const("unwind");
 }
+
+impl super::TargetFeature {
+    pub const a: TargetFeature = TargetFeature::new_const("a");
+    pub const aes: TargetFeature = TargetFeature::new_const("aes");
+    pub const avx: TargetFeature = TargetFeature::new_const("avx");
+    pub const avx2: TargetFeature = TargetFeature::new_const("avx2");
+    pub const b: TargetFeature = TargetFeature::new_const("b");
+    pub const bmi1: TargetFeature = TargetFeature::new_const("bmi1");
+    pub const bmi2: TargetFeature = TargetFeature::new_const("bmi2");
+    pub const bulk_memory: TargetFeature = TargetFeature::new_const("bulk-memory");
+    pub const c: TargetFeature = TargetFeature::new_const("c");
+    pub const cmpxchg16b: TargetFeature = TargetFeature::new_const("cmpxchg16b");
+    pub const crc: TargetFeature = TargetFeature::new_const("crc");
+    pub const crt_static: TargetFeature = TargetFeature::new_const("crt-static");
+    pub const d: TargetFeature = TargetFeature::new_const("d");
+    pub const dit: TargetFeature = TargetFeature::new_const("dit");
+    pub const dotprod: TargetFeature = TargetFeature::new_const("dotprod");
+    pub const dpb: TargetFeature = TargetFeature::new_const("dpb");
+    pub const dpb2: TargetFeature = TargetFeature::new_const("dpb2");
+    pub const f: TargetFeature = TargetFeature::new_const("f");
+    pub const f16c: TargetFeature = TargetFeature::new_const("f16c");
+    pub const fcma: TargetFeature = TargetFeature::new_const("fcma");
+    pub const fhm: TargetFeature = TargetFeature::new_const("fhm");
+    pub const flagm: TargetFeature = TargetFeature::new_const("flagm");
+    pub const fma: TargetFeature = TargetFeature::new_const("fma");
+    pub const fp16: TargetFeature = TargetFeature::new_const("fp16");
+    pub const frintts: TargetFeature = TargetFeature::new_const("frintts");
+    pub const fxsr: TargetFeature = TargetFeature::new_const("fxsr");
+    pub const jsconv: TargetFeature = TargetFeature::new_const("jsconv");
+    pub const lor: TargetFeature = TargetFeature::new_const("lor");
+    pub const lse: TargetFeature = TargetFeature::new_const("lse");
+    pub const lsx: TargetFeature = TargetFeature::new_const("lsx");
+    pub const lzcnt: TargetFeature = TargetFeature::new_const("lzcnt");
+    pub const m: TargetFeature = TargetFeature::new_const("m");
+    pub const movbe: TargetFeature = TargetFeature::new_const("movbe");
+    pub const multivalue: TargetFeature = TargetFeature::new_const("multivalue");
+    pub const mutable_globals: TargetFeature = TargetFeature::new_const("mutable-globals");
+    pub const neon: TargetFeature = TargetFeature::new_const("neon");
+    pub const nontrapping_fptoint: TargetFeature = TargetFeature::new_const("nontrapping-fptoint");
+    pub const paca: TargetFeature = TargetFeature::new_const("paca");
+    pub const pacg: TargetFeature = TargetFeature::new_const("pacg");
+    pub const pan: TargetFeature = TargetFeature::new_const("pan");
+    pub const pmuv3: TargetFeature = TargetFeature::new_const("pmuv3");
+    pub const popcnt: TargetFeature = TargetFeature::new_const("popcnt");
+    pub const ras: TargetFeature = TargetFeature::new_const("ras");
+    pub const rcpc: TargetFeature = TargetFeature::new_const("rcpc");
+    pub const rcpc2: TargetFeature = TargetFeature::new_const("rcpc2");
+    pub const rdm: TargetFeature = TargetFeature::new_const("rdm");
+    pub const rdrand: TargetFeature = TargetFeature::new_const("rdrand");
+    pub const rdseed: TargetFeature = TargetFeature::new_const("rdseed");
+    pub const reference_types: TargetFeature = TargetFeature::new_const("reference-types");
+    pub const sb: TargetFeature = TargetFeature::new_const("sb");
+    pub const sha2: TargetFeature = TargetFeature::new_const("sha2");
+    pub const sha3: TargetFeature = TargetFeature::new_const("sha3");
+    pub const sign_ext: TargetFeature = TargetFeature::new_const("sign-ext");
+    pub const ssbs: TargetFeature = TargetFeature::new_const("ssbs");
+    pub const sse: TargetFeature = TargetFeature::new_const("sse");
+    pub const sse2: TargetFeature = TargetFeature::new_const("sse2");
+    pub const sse3: TargetFeature = TargetFeature::new_const("sse3");
+    pub const sse4_1: TargetFeature = TargetFeature::new_const("sse4.1");
+    pub const sse4_2: TargetFeature = TargetFeature::new_const("sse4.2");
+    pub const ssse3: TargetFeature = TargetFeature::new_const("ssse3");
+    pub const vh: TargetFeature = TargetFeature::new_const("vh");
+    pub const xsave: TargetFeature = TargetFeature::new_const("xsave");
+    pub const xsaveopt: TargetFeature = TargetFeature::new_const("xsaveopt");
+    pub const za128rs: TargetFeature = TargetFeature::new_const("za128rs");
+    pub const za64rs: TargetFeature = TargetFeature::new_const("za64rs");
+    pub const zaamo: TargetFeature = TargetFeature::new_const("zaamo");
+    pub const zalrsc: TargetFeature = TargetFeature::new_const("zalrsc");
+    pub const zawrs: TargetFeature = TargetFeature::new_const("zawrs");
+    pub const zba: TargetFeature = TargetFeature::new_const("zba");
+    pub const zbb: TargetFeature = TargetFeature::new_const("zbb");
+    pub const zbs: TargetFeature = TargetFeature::new_const("zbs");
+    pub const zca: TargetFeature = TargetFeature::new_const("zca");
+    pub const zcb: TargetFeature = TargetFeature::new_const("zcb");
+    pub const zcmop: TargetFeature = TargetFeature::new_const("zcmop");
+    pub const zic64b: TargetFeature = TargetFeature::new_const("zic64b");
+    pub const zicbom: TargetFeature = TargetFeature::new_const("zicbom");
+    pub const zicbop: TargetFeature = TargetFeature::new_const("zicbop");
+    pub const zicboz: TargetFeature = TargetFeature::new_const("zicboz");
+    pub const ziccamoa: TargetFeature = TargetFeature::new_const("ziccamoa");
+    pub const ziccif: TargetFeature = TargetFeature::new_const("ziccif");
+    pub const zicclsm: TargetFeature = TargetFeature::new_const("zicclsm");
+    pub const ziccrse: TargetFeature = TargetFeature::new_const("ziccrse");
+    pub const zicntr: TargetFeature = TargetFeature::new_const("zicntr");
+    pub const zicond: TargetFeature = TargetFeature::new_const("zicond");
+    pub const zicsr: TargetFeature = TargetFeature::new_const("zicsr");
+    pub const zifencei: TargetFeature = TargetFeature::new_const("zifencei");
+    pub const zihintntl: TargetFeature = TargetFeature::new_const("zihintntl");
+    pub const zihintpause: TargetFeature = TargetFeature::new_const("zihintpause");
+    pub const zihpm: TargetFeature = TargetFeature::new_const("zihpm");
+    pub const zimop: TargetFeature = TargetFeature::new_const("zimop");
+    pub const zkt: TargetFeature = TargetFeature::new_const("zkt");
+}
@@ -0,0 +1,236 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::{Expression, Predicate, TargetMatcher, TargetPredicate},
+    targets::{
+        Abi, Arch, Endian, Env, Families, Family, HasAtomic, HasAtomics, Os, Panic, ReliableFloats,
+        TargetFeature, TargetFeatures, TargetInfo, TargetMetadata, Triple, Vendor,
+    },
+};
+
+/// The full set of cfgs that rustc sets for a particular compilation, as
+/// printed by `rustc --print cfg`
+///
+/// Unlike the [builtin](crate::targets::ALL_BUILTINS) targets, this captures
+/// exactly the cfgs of a specific toolchain, target, and set of compiler
+/// flags, so that expressions can be evaluated against the same data that
+/// rustc would use.
+///
+/// ```
+/// use cfg_expr::{targets::CfgSet, Expression};
+///
+/// let output = r#"debug_assertions
+/// panic="unwind"
+/// target_arch="x86_64"
+/// target_endian="little"
+/// target_env="gnu"
+/// target_family="unix"
+/// target_feature="sse2"
+/// target_has_atomic="64"
+/// target_has_atomic="ptr"
+/// target_os="linux"
+/// target_pointer_width="64"
+/// target_vendor="unknown"
+/// unix
+/// "#;
+///
+/// let cfgs = CfgSet::from_print_cfg("x86_64-unknown-linux-gnu", output).unwrap();
+///
+/// let expr = Expression::parse(r#"all(unix, target_feature = "sse2", debug_assertions)"#).unwrap();
+/// assert_eq!(cfgs.eval(&expr), Some(true));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CfgSet {
+    /// The target information, including the `panic` strategy and the
+    /// enabled `target_feature`s
+    pub target: TargetInfo,
+    /// Whether `debug_assertions` are enabled
+    pub debug_assertions: bool,
+    /// Every other bare cfg, eg. `overflow_checks`, or `my_flag` from `--cfg my_flag`
+    pub flags: Vec<String>,
+    /// Every other `key="value"` cfg, eg. `relocation_model="pic"`, or
+    /// `feature="foo"` from `--cfg 'feature="foo"'`
+    pub key_values: Vec<(String, String)>,
+}
+
+impl CfgSet {
+    /// Parses the output of `rustc --print cfg` for the specified target triple
+    ///
+    /// Each line is either a bare `name` or a `name="value"`. The
+    /// `target_arch`, `target_endian`, and `target_pointer_width` cfgs are
+    /// required, while `panic` defaults to `unwind` for output from rustc
+    /// versions that didn't print it. The support metadata isn't part of the
+    /// cfgs, so [`TargetInfo::metadata`] is always unknown.
+    pub fn from_print_cfg(triple: &str, output: &str) -> Result<Self, ParseError> {
+        let err = |span: std::ops::Range<usize>, reason: Reason| ParseError {
+            original: output.to_owned(),
+            span,
+            reason,
+        };
+
+        let mut abi = None;
+        let mut arch = None;
+        let mut endian = None;
+        let mut env = None;
+        let mut families = Vec::new();
+        let mut has_atomics = Vec::new();
+        let mut has_atomics_load_store = Vec::new();
+        let mut has_atomics_equal_alignment = Vec::new();
+        let mut os = None;
+        let mut panic = None;
+        let mut pointer_width = None;
+        let mut vendor = None;
+
+        let mut target_features = Vec::new();
+        let mut thread_local = false;
+        let mut reliable_floats = ReliableFloats::NONE;
+        let mut debug_assertions = false;
+        let mut flags = Vec::new();
+        let mut key_values = Vec::new();
+
+        let mut offset = 0;
+        for line in output.split_inclusive('\n') {
+            let start = offset;
+            offset += line.len();
+
+            let line = line.trim_end();
+            if line.is_empty() {
+                continue;
+            }
+
+            let Some((key, val)) = line.split_once('=') else {
+                match line {
+                    "debug_assertions" => debug_assertions = true,
+                    // These are implied by the target_family
+                    "unix" | "windows" => {}
+                    "target_thread_local" => thread_local = true,
+                    "target_has_reliable_f16" => reliable_floats.f16 = true,
+                    "target_has_reliable_f16_math" => reliable_floats.f16_math = true,
+                    "target_has_reliable_f128" => reliable_floats.f128 = true,
+                    "target_has_reliable_f128_math" => reliable_floats.f128_math = true,
+                    flag => flags.push(flag.to_owned()),
+                }
+
+                continue;
+            };
+
+            let vstart = start + key.len() + 1;
+            let Some(val) = val.strip_prefix('"').and_then(|val| val.strip_suffix('"')) else {
+                return Err(err(vstart..start + line.len(), Reason::UnclosedQuotes));
+            };
+            let vspan = vstart + 1..vstart + 1 + val.len();
+
+            let non_empty = || (!val.is_empty()).then(|| val.to_owned());
+
+            match key {
+                "panic" => panic = Some(Panic::new(val.to_owned())),
+                "target_abi" => abi = non_empty().map(Abi::new),
+                "target_arch" => arch = Some(Arch::new(val.to_owned())),
+                "target_endian" => {
+                    endian = Some(val.parse::<Endian>().map_err(|reason| err(vspan, reason))?);
+                }
+                "target_env" => env = non_empty().map(Env::new),
+                "target_family" => families.push(Family::new(val.to_owned())),
+                "target_feature" => target_features.push(TargetFeature::new(val.to_owned())),
+                "target_has_atomic" => has_atomics.push(
+                    val.parse::<HasAtomic>()
+                        .map_err(|_err| err(vspan, Reason::InvalidHasAtomic))?,
+                ),
+                "target_has_atomic_load_store" => has_atomics_load_store.push(
+                    val.parse::<HasAtomic>()
+                        .map_err(|_err| err(vspan, Reason::InvalidHasAtomic))?,
+                ),
+                // Newer nightlies have renamed this to primitive_alignment
+                "target_has_atomic_equal_alignment" | "target_has_atomic_primitive_alignment" => {
+                    has_atomics_equal_alignment.push(
+                        val.parse::<HasAtomic>()
+                            .map_err(|_err| err(vspan, Reason::InvalidHasAtomic))?,
+                    );
+                }
+                "target_os" => os = (val != "none").then(|| Os::new(val.to_owned())),
+                "target_pointer_width" => {
+                    pointer_width = Some(
+                        val.parse::<u8>()
+                            .map_err(|_err| err(vspan, Reason::InvalidInteger))?,
+                    );
+                }
+                "target_vendor" => vendor = non_empty().map(Vendor::new),
+                key => key_values.push((key.to_owned(), val.to_owned())),
+            }
+        }
+
+        let missing = |expected: &'static [&'static str]| {
+            err(output.len()..output.len(), Reason::Unexpected(expected))
+        };
+
+        let target = TargetInfo {
+            triple: Triple::new(triple.to_owned()),
+            os,
+            abi,
+            arch: arch.ok_or_else(|| missing(&["target_arch"]))?,
+            env,
+            vendor,
+            families: Families::new(families),
+            pointer_width: pointer_width.ok_or_else(|| missing(&["target_pointer_width"]))?,
+            endian: endian.ok_or_else(|| missing(&["target_endian"]))?,
+            has_atomics: HasAtomics::new(has_atomics),
+            has_atomics_load_store: HasAtomics::new(has_atomics_load_store),
+            has_atomics_equal_alignment: HasAtomics::new(has_atomics_equal_alignment),
+            panic: panic.unwrap_or(Panic::unwind),
+            target_features: TargetFeatures::new(target_features),
+            thread_local,
+            reliable_floats,
+            metadata: TargetMetadata::UNKNOWN,
+        };
+
+        Ok(Self {
+            target,
+            debug_assertions,
+            flags,
+            key_values,
+        })
+    }
+
+    /// Evaluates a single predicate against this set of cfgs
+    ///
+    /// `version()` predicates depend on the version of rustc, which isn't
+    /// part of the `--print cfg` output, so they evaluate to `None`. Every
+    /// other predicate is true only if the cfg was printed.
+    pub fn eval_predicate(&self, pred: &Predicate<'_>) -> Option<bool> {
+        let has_flag = |flag: &str| self.flags.iter().any(|f| f == flag);
+        let has_kv =
+            |key: &str, val: &str| self.key_values.iter().any(|(k, v)| k == key && v == val);
+
+        Some(match pred {
+            Predicate::Target(tp) => self.target.matches(tp),
+            Predicate::TargetFeature(feat) => self.target.target_features.contains(feat),
+            Predicate::DebugAssertions => self.debug_assertions,
+            Predicate::Test => has_flag("test"),
+            Predicate::ProcMacro => has_flag("proc_macro"),
+            Predicate::OverflowChecks => has_flag("overflow_checks"),
+            Predicate::UbChecks => has_flag("ub_checks"),
+            Predicate::Doctest => has_flag("doctest"),
+            Predicate::Miri => has_flag("miri"),
+            Predicate::ContractChecks => has_flag("contract_checks"),
+            Predicate::Sanitize(san) => has_kv("sanitize", san.as_str()),
+            Predicate::FmtDebug(fd) => has_kv("fmt_debug", fd.as_str()),
+            Predicate::RelocationModel(rm) => has_kv("relocation_model", rm.as_str()),
+            Predicate::Feature(feat) => has_kv("feature", feat),
+            Predicate::Flag(flag) => has_flag(flag),
+            Predicate::KeyValue { key, val } => has_kv(key, val),
+            Predicate::Version { .. } => return None,
+        })
+    }
+
+    /// Evaluates the expression against this set of cfgs, see
+    /// [`Self::eval_predicate`]
+    pub fn eval(&self, expr: &Expression) -> Option<bool> {
+        expr.eval(|pred| self.eval_predicate(pred))
+    }
+}
+
+impl TargetMatcher for CfgSet {
+    fn matches(&self, tp: &TargetPredicate) -> bool {
+        self.target.matches(tp)
+    }
+}
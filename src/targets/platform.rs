@@ -0,0 +1,123 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::{Dialect, Expression, ParseOptions, Predicate, TargetMatcher},
+    targets::{TargetInfo, Triple, get_builtin_target_by_triple},
+};
+
+/// A Cargo platform specification, ie. the `<spec>` in a
+/// `[target.<spec>.dependencies]` table of a manifest
+///
+/// ```
+/// use cfg_expr::targets::{get_builtin_target_by_triple, Platform};
+///
+/// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+///
+/// let platform: Platform = r#"cfg(all(unix, feature = "std"))"#.parse().unwrap();
+/// assert!(platform.matches(linux, |pred| pred.to_string() == r#"feature = "std""#));
+///
+/// let platform: Platform = "x86_64-unknown-linux-gnu".parse().unwrap();
+/// assert!(platform.matches(linux, |_| false));
+/// assert_eq!(platform.to_string(), "x86_64-unknown-linux-gnu");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum Platform {
+    /// A bare target triple, eg. `x86_64-unknown-linux-gnu`
+    Triple(Triple),
+    /// A `cfg()` expression, eg. `cfg(unix)`
+    Cfg(Expression),
+}
+
+impl Platform {
+    /// Checks if this platform matches the target
+    ///
+    /// A triple only matches the target with the same triple. For a `cfg()`
+    /// expression, each [`Predicate::Target`] is matched against the target,
+    /// while every other predicate, eg. `feature` or `target_feature`, is
+    /// evaluated by the closure.
+    pub fn matches<EP>(&self, target: &TargetInfo, mut eval_predicate: EP) -> bool
+    where
+        EP: FnMut(&Predicate<'_>) -> bool,
+    {
+        match self {
+            Self::Triple(triple) => target.triple == *triple,
+            Self::Cfg(expr) => expr.eval(|pred| match pred {
+                Predicate::Target(tp) => target.matches(tp),
+                other => eval_predicate(other),
+            }),
+        }
+    }
+}
+
+/// Parses either a `cfg()` expression or a target triple
+///
+/// The expression is parsed with the rules of [`Dialect::Cargo`], so it
+/// accepts exactly what Cargo does, eg. `cfg(feature)` is a
+/// [`Predicate::Flag`], while tabs and `version()` are rejected.
+///
+/// A triple must be one of the [builtin](crate::targets::ALL_BUILTINS)
+/// targets, or, with the `targets` feature, any triple that
+/// `target_lexicon` can parse. The triples of custom targets can be used by
+/// constructing a [`Platform::Triple`] directly.
+impl std::str::FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(inner) = s.strip_prefix("cfg(").and_then(|s| s.strip_suffix(')')) {
+            let options = ParseOptions {
+                dialect: Dialect::Cargo,
+                ..Default::default()
+            };
+
+            return Expression::parse_with_options(inner, options)
+                .map(Self::Cfg)
+                .map_err(|err| {
+                    // Point the error at the same characters in the full spec
+                    let span = err.span.start + 4..err.span.end + 4;
+                    ParseError {
+                        original: s.to_owned(),
+                        span,
+                        reason: err.reason,
+                    }
+                });
+        }
+
+        let err = |span: std::ops::Range<usize>, reason: Reason| ParseError {
+            original: s.to_owned(),
+            span,
+            reason,
+        };
+
+        if s.is_empty() {
+            return Err(err(0..0, Reason::Empty));
+        }
+
+        // The same characters that Cargo allows in a platform name
+        if let Some((i, c)) = s
+            .char_indices()
+            .find(|(_, c)| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        {
+            return Err(err(i..i + c.len_utf8(), Reason::InvalidCharacters));
+        }
+
+        if let Some(builtin) = get_builtin_target_by_triple(s) {
+            return Ok(Self::Triple(builtin.triple.clone()));
+        }
+
+        #[cfg(feature = "targets")]
+        if s.parse::<target_lexicon::Triple>().is_ok() {
+            return Ok(Self::Triple(Triple::new(s.to_owned())));
+        }
+
+        Err(err(0..s.len(), Reason::UnknownBuiltin))
+    }
+}
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Triple(triple) => f.write_str(triple.as_str()),
+            Self::Cfg(expr) => write!(f, "cfg({})", expr.original()),
+        }
+    }
+}
@@ -0,0 +1,667 @@
+use crate::{
+    error::{Reason, TargetSpecError, TargetSpecReason},
+    targets::{
+        Abi, Arch, Endian, Env, Families, Family, HasAtomic, HasAtomics, Os, Panic, ReliableFloats,
+        TargetFeatures, TargetInfo, TargetMetadata, Triple, Vendor,
+    },
+};
+use std::{
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+impl TargetInfo {
+    /// Parses a [target specification](https://doc.rust-lang.org/rustc/targets/custom.html)
+    /// JSON file, as used by `rustc --target <path>.json` and printed by
+    /// `rustc -Z unstable-options --print target-spec-json`
+    ///
+    /// The `arch` and `target-pointer-width` fields are required, every other
+    /// field uses the same default as rustc if it is not specified. The triple
+    /// is taken from the `llvm-target` field, if you know the actual name of
+    /// the target, eg. the file stem, you can replace it. The `metadata`
+    /// object, if present, is mapped to [`TargetInfo::metadata`].
+    ///
+    /// The target features enabled by the `features` field are LLVM features,
+    /// which rustc converts into the `target_feature` cfgs itself, so
+    /// [`TargetInfo::target_features`] is always empty. Use
+    /// [`CfgSet`](crate::targets::CfgSet) if you need the exact set of
+    /// features. Likewise, the `target_has_reliable_*` cfgs are determined by
+    /// the codegen backend, so [`TargetInfo::reliable_floats`] is always
+    /// [`ReliableFloats::NONE`].
+    ///
+    /// ```
+    /// use cfg_expr::targets::{TargetInfo, Arch, HasAtomic, Panic};
+    ///
+    /// let board = TargetInfo::from_target_spec_json(r#"{
+    ///     "arch": "arm",
+    ///     "llvm-target": "thumbv7em-none-eabihf",
+    ///     "max-atomic-width": 32,
+    ///     "panic-strategy": "abort",
+    ///     "target-pointer-width": "32"
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(board.triple.as_str(), "thumbv7em-none-eabihf");
+    /// assert_eq!(board.arch, Arch::arm);
+    /// assert_eq!(board.os, None);
+    /// assert!(board.has_atomics.contains(HasAtomic::Pointer));
+    /// assert!(!board.has_atomics.contains(HasAtomic::IntegerSize(64)));
+    /// assert_eq!(board.panic, Panic::abort);
+    /// ```
+    pub fn from_target_spec_json(json: &str) -> Result<Self, TargetSpecError> {
+        let root = Parser {
+            src: json,
+            pos: 0,
+            depth: 0,
+        }
+        .parse()?;
+
+        let err = |span: Range<usize>, reason: TargetSpecReason| TargetSpecError {
+            original: json.to_owned(),
+            span,
+            reason,
+        };
+
+        let Value::Object(fields) = &root.value else {
+            return Err(err(root.span, TargetSpecReason::InvalidType("object")));
+        };
+
+        let get = |key: &str| fields.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+
+        let string = |key: &str| -> Result<Option<String>, TargetSpecError> {
+            match get(key) {
+                None => Ok(None),
+                Some(Json {
+                    value: Value::String(s),
+                    ..
+                }) => Ok(Some(s.clone())),
+                Some(other) => Err(err(
+                    other.span.clone(),
+                    TargetSpecReason::InvalidType("string"),
+                )),
+            }
+        };
+
+        // Integers have been specified as both numbers and strings over time,
+        // eg. `target-pointer-width`
+        let integer = |key: &str| -> Result<Option<u16>, TargetSpecError> {
+            match get(key) {
+                None => Ok(None),
+                Some(Json {
+                    value: Value::Number(s),
+                    span,
+                }) => s
+                    .parse()
+                    .map(Some)
+                    .map_err(|_err| err(span.clone(), TargetSpecReason::InvalidInteger)),
+                Some(Json {
+                    value: Value::String(s),
+                    span,
+                }) => s.parse().map(Some).map_err(|_err| {
+                    err(
+                        span.start + 1..span.end - 1,
+                        TargetSpecReason::InvalidInteger,
+                    )
+                }),
+                Some(other) => Err(err(
+                    other.span.clone(),
+                    TargetSpecReason::InvalidType("integer"),
+                )),
+            }
+        };
+
+        let missing = |field: &'static str| {
+            err(
+                root.span.end - 1..root.span.end,
+                TargetSpecReason::MissingField(field),
+            )
+        };
+
+        let non_empty = |s: Option<String>| s.filter(|s| !s.is_empty());
+
+        let arch = string("arch")?.ok_or_else(|| missing("arch"))?;
+        let pointer_width =
+            integer("target-pointer-width")?.ok_or_else(|| missing("target-pointer-width"))?;
+        let pointer_width = u8::try_from(pointer_width).map_err(|_err| {
+            err(
+                get("target-pointer-width").unwrap().span.clone(),
+                TargetSpecReason::InvalidInteger,
+            )
+        })?;
+
+        let endian = match get("target-endian") {
+            None => Endian::little,
+            Some(Json {
+                value: Value::String(s),
+                span,
+            }) => s.parse().map_err(|reason| {
+                let expected = match reason {
+                    Reason::Unexpected(expected) => expected,
+                    _ => &[],
+                };
+                err(
+                    span.start + 1..span.end - 1,
+                    TargetSpecReason::InvalidValue(expected),
+                )
+            })?,
+            Some(other) => {
+                return Err(err(
+                    other.span.clone(),
+                    TargetSpecReason::InvalidType("string"),
+                ));
+            }
+        };
+
+        // This has been both a single string and a list of strings
+        let families = match get("target-family") {
+            None => Vec::new(),
+            Some(Json {
+                value: Value::String(s),
+                ..
+            }) => vec![Family::new(s.clone())],
+            Some(Json {
+                value: Value::Array(fams),
+                ..
+            }) => fams
+                .iter()
+                .map(|fam| match &fam.value {
+                    Value::String(s) => Ok(Family::new(s.clone())),
+                    _ => Err(err(
+                        fam.span.clone(),
+                        TargetSpecReason::InvalidType("string"),
+                    )),
+                })
+                .collect::<Result<_, _>>()?,
+            Some(other) => {
+                return Err(err(
+                    other.span.clone(),
+                    TargetSpecReason::InvalidType("array"),
+                ));
+            }
+        };
+
+        // rustc only sets `target_has_atomic` for the sizes between the min
+        // and max widths, and only if compare and swap is supported
+        let max_atomic_width = integer("max-atomic-width")?.unwrap_or(u16::from(pointer_width));
+        let min_atomic_width = integer("min-atomic-width")?.unwrap_or(8);
+        let flag = |key: &str, default: bool| match get(key) {
+            None => Ok(default),
+            Some(Json {
+                value: Value::Bool(b),
+                ..
+            }) => Ok(*b),
+            Some(other) => Err(err(
+                other.span.clone(),
+                TargetSpecReason::InvalidType("boolean"),
+            )),
+        };
+        let atomic_cas = flag("atomic-cas", true)?;
+
+        // Unlike the other fields, each of these may also be null
+        let metadata = match get("metadata") {
+            None
+            | Some(Json {
+                value: Value::Null, ..
+            }) => TargetMetadata::UNKNOWN,
+            Some(Json {
+                value: Value::Object(meta),
+                ..
+            }) => {
+                let get = |key: &str| {
+                    meta.iter()
+                        .find(|(k, _)| k == key)
+                        .map(|(_, v)| v)
+                        .filter(|v| !matches!(v.value, Value::Null))
+                };
+
+                let flag = |key: &str| match get(key) {
+                    None => Ok(None),
+                    Some(Json {
+                        value: Value::Bool(b),
+                        ..
+                    }) => Ok(Some(*b)),
+                    Some(other) => Err(err(
+                        other.span.clone(),
+                        TargetSpecReason::InvalidType("boolean"),
+                    )),
+                };
+
+                let tier = match get("tier") {
+                    None => None,
+                    Some(Json {
+                        value: Value::Number(n),
+                        span,
+                    }) => Some(
+                        n.parse()
+                            .map_err(|_err| err(span.clone(), TargetSpecReason::InvalidInteger))?,
+                    ),
+                    Some(other) => {
+                        return Err(err(
+                            other.span.clone(),
+                            TargetSpecReason::InvalidType("integer"),
+                        ));
+                    }
+                };
+
+                TargetMetadata {
+                    tier,
+                    std: flag("std")?,
+                    host_tools: flag("host_tools")?,
+                }
+            }
+            Some(other) => {
+                return Err(err(
+                    other.span.clone(),
+                    TargetSpecReason::InvalidType("object"),
+                ));
+            }
+        };
+
+        // Loads and stores are supported for the same sizes, even without
+        // compare and swap, and the alignment is equal if the data layout
+        // aligns the integer of that size to the size itself
+        let (int_aligns, pointer_align) =
+            data_layout_alignments(string("data-layout")?.as_deref().unwrap_or_default());
+
+        let mut has_atomics = Vec::new();
+        let mut has_atomics_load_store = Vec::new();
+        let mut has_atomics_equal_alignment = Vec::new();
+
+        let mut insert = |has_atomic: HasAtomic, width: u16, align: u16| {
+            if !(min_atomic_width..=max_atomic_width).contains(&width) {
+                return;
+            }
+
+            if atomic_cas {
+                has_atomics.push(has_atomic);
+            }
+            has_atomics_load_store.push(has_atomic);
+            if align == width {
+                has_atomics_equal_alignment.push(has_atomic);
+            }
+        };
+
+        for (width, align) in [8, 16, 32, 64, 128].into_iter().zip(int_aligns) {
+            insert(HasAtomic::IntegerSize(width), width, align);
+        }
+        insert(HasAtomic::Pointer, u16::from(pointer_width), pointer_align);
+
+        Ok(Self {
+            triple: Triple::new(string("llvm-target")?.unwrap_or_default()),
+            os: string("os")?.filter(|os| os != "none").map(Os::new),
+            abi: non_empty(string("abi")?).map(Abi::new),
+            arch: Arch::new(arch),
+            env: non_empty(string("env")?).map(Env::new),
+            vendor: Some(Vendor::new(
+                non_empty(string("vendor")?).unwrap_or_else(|| "unknown".to_owned()),
+            )),
+            families: Families::new(families),
+            pointer_width,
+            endian,
+            has_atomics: HasAtomics::new(has_atomics),
+            has_atomics_load_store: HasAtomics::new(has_atomics_load_store),
+            has_atomics_equal_alignment: HasAtomics::new(has_atomics_equal_alignment),
+            panic: string("panic-strategy")?.map_or(Panic::unwind, Panic::new),
+            target_features: TargetFeatures::new_const(&[]),
+            thread_local: flag("has-thread-local", false)?,
+            reliable_floats: ReliableFloats::NONE,
+            metadata,
+        })
+    }
+}
+
+/// Retrieves the ABI alignments, in bits, of the 8, 16, 32, 64, and 128 bit
+/// integers, and of pointers, from an LLVM data layout
+///
+/// Anything that isn't specified uses the same default as rustc, where the
+/// 128 bit integer is aligned the same as the largest integer between 64 and
+/// 128 bits that is specified.
+fn data_layout_alignments(data_layout: &str) -> ([u16; 5], u16) {
+    let mut ints = [8, 16, 32, 32, 32];
+    let mut pointer = 64;
+    let mut i128_source = 64;
+
+    for spec in data_layout.split('-') {
+        let mut parts = spec.split(':');
+        let kind = parts.next().unwrap_or_default();
+
+        if let Some(address_space) = kind.strip_prefix('p') {
+            // Only the default address space is used for pointers
+            if matches!(address_space, "" | "0") {
+                if let Some(align) = parts.nth(1).and_then(|align| align.parse().ok()) {
+                    pointer = align;
+                }
+            }
+        } else if let Some(bits) = kind.strip_prefix('i').and_then(|bits| bits.parse().ok()) {
+            let Some(align) = parts.next().and_then(|align| align.parse().ok()) else {
+                continue;
+            };
+
+            if let Some(i) = [8, 16, 32, 64].iter().position(|size| *size == bits) {
+                ints[i] = align;
+            }
+
+            if (i128_source..=128).contains(&bits) {
+                i128_source = bits;
+                ints[4] = align;
+            }
+        }
+    }
+
+    (ints, pointer)
+}
+
+/// Searches each directory in the `RUST_TARGET_PATH` environment variable for
+/// a `<name>.json` target specification, the same as rustc does for
+/// `--target <name>`
+///
+/// See [`find_target_spec_in`] to search other directories.
+pub fn find_target_spec(name: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("RUST_TARGET_PATH")?;
+    find_target_spec_in(name, std::env::split_paths(&path))
+}
+
+/// Searches each of the specified directories for a `<name>.json` target
+/// specification, returning the first one that exists
+///
+/// ```
+/// let dir = std::env::temp_dir().join("cfg-expr-doc-find-target-spec");
+/// std::fs::create_dir_all(&dir).unwrap();
+/// std::fs::write(dir.join("board.json"), "{}").unwrap();
+///
+/// assert_eq!(
+///     cfg_expr::targets::find_target_spec_in("board", [&dir]),
+///     Some(dir.join("board.json")),
+/// );
+/// assert!(cfg_expr::targets::find_target_spec_in("nope", [&dir]).is_none());
+/// ```
+pub fn find_target_spec_in(
+    name: &str,
+    dirs: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> Option<PathBuf> {
+    let file_name = format!("{name}.json");
+
+    dirs.into_iter()
+        .map(|dir| dir.as_ref().join(&file_name))
+        .find(|path| path.is_file())
+}
+
+/// A JSON value and its span in the source
+struct Json<'a> {
+    value: Value<'a>,
+    span: Range<usize>,
+}
+
+enum Value<'a> {
+    Null,
+    Bool(bool),
+    /// The number is kept as a string as we only care about integers
+    Number(&'a str),
+    String(String),
+    Array(Vec<Json<'a>>),
+    Object(Vec<(String, Json<'a>)>),
+}
+
+/// The maximum nesting of objects and arrays, the same as `serde_json`, so that
+/// deeply nested input can't overflow the stack
+const MAX_DEPTH: usize = 128;
+
+/// A minimal JSON parser, which is all that is needed for target specifications
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    /// The number of objects and arrays the parser is currently inside
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(mut self) -> Result<Json<'a>, TargetSpecError> {
+        let value = self.value()?;
+        self.skip_whitespace();
+
+        if self.pos < self.src.len() {
+            return Err(self.err(
+                self.pos..self.src.len(),
+                TargetSpecReason::TrailingCharacters,
+            ));
+        }
+
+        Ok(value)
+    }
+
+    fn err(&self, span: Range<usize>, reason: TargetSpecReason) -> TargetSpecError {
+        TargetSpecError {
+            original: self.src.to_owned(),
+            span,
+            reason,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.src[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn expect(
+        &mut self,
+        ch: char,
+        expected: &'static [&'static str],
+    ) -> Result<(), TargetSpecError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(c) if c == ch => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => Err(self.err(
+                self.pos..self.pos + c.len_utf8(),
+                TargetSpecReason::Unexpected(expected),
+            )),
+            None => Err(self.err(self.pos..self.pos, TargetSpecReason::Unexpected(expected))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Json<'a>, TargetSpecError> {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        if matches!(self.peek(), Some('{' | '[')) {
+            if self.depth == MAX_DEPTH {
+                return Err(self.err(start..start + 1, TargetSpecReason::DepthLimitExceeded));
+            }
+
+            self.depth += 1;
+        }
+
+        let value = match self.peek() {
+            Some('{') => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+
+                self.skip_whitespace();
+                if self.peek() == Some('}') {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        self.skip_whitespace();
+                        if self.peek() != Some('"') {
+                            return Err(self.err(
+                                self.pos..self.pos + 1,
+                                TargetSpecReason::Unexpected(&["\"<key>\""]),
+                            ));
+                        }
+                        let key = self.string()?;
+                        self.expect(':', &[":"])?;
+                        let value = self.value()?;
+                        fields.push((key, value));
+
+                        self.skip_whitespace();
+                        match self.peek() {
+                            Some(',') => self.pos += 1,
+                            Some('}') => {
+                                self.pos += 1;
+                                break;
+                            }
+                            None => {
+                                return Err(self
+                                    .err(start..self.src.len(), TargetSpecReason::UnclosedObject));
+                            }
+                            Some(_) => {
+                                return Err(self.err(
+                                    self.pos..self.pos + 1,
+                                    TargetSpecReason::Unexpected(&[",", "}"]),
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                self.depth -= 1;
+                Value::Object(fields)
+            }
+            Some('[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+
+                self.skip_whitespace();
+                if self.peek() == Some(']') {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        items.push(self.value()?);
+
+                        self.skip_whitespace();
+                        match self.peek() {
+                            Some(',') => self.pos += 1,
+                            Some(']') => {
+                                self.pos += 1;
+                                break;
+                            }
+                            None => {
+                                return Err(self
+                                    .err(start..self.src.len(), TargetSpecReason::UnclosedArray));
+                            }
+                            Some(_) => {
+                                return Err(self.err(
+                                    self.pos..self.pos + 1,
+                                    TargetSpecReason::Unexpected(&[",", "]"]),
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                self.depth -= 1;
+                Value::Array(items)
+            }
+            Some('"') => Value::String(self.string()?),
+            Some('-' | '0'..='9') => {
+                let rest = &self.src[self.pos..];
+                let len = rest
+                    .find(|c: char| !matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9'))
+                    .unwrap_or(rest.len());
+                self.pos += len;
+                Value::Number(&rest[..len])
+            }
+            _ => {
+                let rest = &self.src[self.pos..];
+                let (value, len) = if rest.starts_with("true") {
+                    (Value::Bool(true), 4)
+                } else if rest.starts_with("false") {
+                    (Value::Bool(false), 5)
+                } else if rest.starts_with("null") {
+                    (Value::Null, 4)
+                } else {
+                    let end = rest.chars().next().map_or(0, char::len_utf8);
+                    return Err(self.err(
+                        self.pos..self.pos + end,
+                        TargetSpecReason::Unexpected(&[
+                            "{", "[", "\"", "<number>", "true", "false", "null",
+                        ]),
+                    ));
+                };
+
+                self.pos += len;
+                value
+            }
+        };
+
+        Ok(Json {
+            value,
+            span: start..self.pos,
+        })
+    }
+
+    /// Parses a string, the current position must be at the opening quote
+    fn string(&mut self) -> Result<String, TargetSpecError> {
+        let start = self.pos;
+        self.pos += 1;
+
+        let mut s = String::new();
+        let mut chars = self.src[self.pos..].char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(s);
+                }
+                '\\' => {
+                    let escaped = match chars.next() {
+                        Some((_, c @ ('"' | '\\' | '/'))) => c,
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 't')) => '\t',
+                        Some((j, 'u')) => {
+                            let hex = |at: usize| {
+                                self.src
+                                    .get(self.pos + at..self.pos + at + 4)
+                                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                            };
+
+                            let invalid = || {
+                                self.err(
+                                    self.pos + i..self.pos + j + 5,
+                                    TargetSpecReason::InvalidEscape,
+                                )
+                            };
+
+                            let mut code = hex(j + 1).ok_or_else(invalid)?;
+                            chars.nth(3);
+
+                            // Surrogate pairs are encoded as two escapes
+                            if (0xd800..0xdc00).contains(&code) {
+                                let low = self.src[self.pos + j + 5..]
+                                    .strip_prefix("\\u")
+                                    .and_then(|_| hex(j + 7))
+                                    .filter(|low| (0xdc00..0xe000).contains(low))
+                                    .ok_or_else(invalid)?;
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                                chars.nth(5);
+                            }
+
+                            char::from_u32(code).ok_or_else(invalid)?
+                        }
+                        _ => {
+                            return Err(self.err(
+                                self.pos + i..self.pos + i + 2,
+                                TargetSpecReason::InvalidEscape,
+                            ));
+                        }
+                    };
+
+                    s.push(escaped);
+                }
+                c => s.push(c),
+            }
+        }
+
+        Err(self.err(start..self.src.len(), TargetSpecReason::UnclosedString))
+    }
+}
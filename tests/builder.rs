@@ -0,0 +1,213 @@
+use cfg_expr::{
+    Expression,
+    error::Reason,
+    expr::{
+        Dialect, FmtDebug, ParseOptions, Predicate as P, RelocationModel, Sanitizer,
+        TargetPredicate as TP,
+    },
+    targets::*,
+};
+
+/// Ensures the built expression can be parsed back into an expression with the
+/// same string and predicates
+fn roundtrip(built: &Expression) {
+    let parsed = Expression::parse(built.original()).unwrap();
+
+    similar_asserts::assert_eq!(built.original(), parsed.original());
+    similar_asserts::assert_eq!(
+        built.predicates().collect::<Vec<_>>(),
+        parsed.predicates().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn predicates() {
+    let preds = [
+        (P::Target(TP::Abi(Abi::eabihf)), r#"target_abi = "eabihf""#),
+        (
+            P::Target(TP::Arch(Arch::x86_64)),
+            r#"target_arch = "x86_64""#,
+        ),
+        (
+            P::Target(TP::Endian(Endian::big)),
+            r#"target_endian = "big""#,
+        ),
+        (P::Target(TP::Env(Env::musl)), r#"target_env = "musl""#),
+        (P::Target(TP::Family(Family::unix)), "unix"),
+        (P::Target(TP::Family(Family::windows)), "windows"),
+        (
+            P::Target(TP::Family(Family::wasm)),
+            r#"target_family = "wasm""#,
+        ),
+        (
+            P::Target(TP::HasAtomic(HasAtomic::Pointer)),
+            r#"target_has_atomic = "ptr""#,
+        ),
+        (
+            P::Target(TP::HasAtomic(HasAtomic::IntegerSize(64))),
+            r#"target_has_atomic = "64""#,
+        ),
+        (P::Target(TP::Os(Os::windows)), r#"target_os = "windows""#),
+        (P::Target(TP::Panic(Panic::abort)), r#"panic = "abort""#),
+        (
+            P::Target(TP::PointerWidth(32)),
+            r#"target_pointer_width = "32""#,
+        ),
+        (
+            P::Target(TP::Vendor(Vendor::apple)),
+            r#"target_vendor = "apple""#,
+        ),
+        (
+            P::Target(TP::HasAtomicLoadStore(HasAtomic::IntegerSize(16))),
+            r#"target_has_atomic_load_store = "16""#,
+        ),
+        (
+            P::Target(TP::HasAtomicEqualAlignment(HasAtomic::Pointer)),
+            r#"target_has_atomic_equal_alignment = "ptr""#,
+        ),
+        (P::Target(TP::ThreadLocal), "target_thread_local"),
+        (
+            P::Target(TP::HasReliableF16Math),
+            "target_has_reliable_f16_math",
+        ),
+        (P::Test, "test"),
+        (P::DebugAssertions, "debug_assertions"),
+        (P::ProcMacro, "proc_macro"),
+        (P::OverflowChecks, "overflow_checks"),
+        (P::UbChecks, "ub_checks"),
+        (P::Doctest, "doctest"),
+        (P::Miri, "miri"),
+        (P::ContractChecks, "contract_checks"),
+        (
+            P::Sanitize(Sanitizer::ShadowCallStack),
+            r#"sanitize = "shadow-call-stack""#,
+        ),
+        (P::FmtDebug(FmtDebug::Shallow), r#"fmt_debug = "shallow""#),
+        (
+            P::RelocationModel(RelocationModel::DynamicNoPic),
+            r#"relocation_model = "dynamic-no-pic""#,
+        ),
+        (P::Feature("cool"), r#"feature = "cool""#),
+        (P::TargetFeature("sse4.2"), r#"target_feature = "sse4.2""#),
+        (P::Flag("key"), "key"),
+        (
+            P::KeyValue {
+                key: "key",
+                val: "",
+            },
+            r#"key = """#,
+        ),
+    ];
+
+    for (pred, expected) in preds {
+        let built = Expression::try_from(pred).unwrap();
+
+        assert_eq!(built.original(), expected);
+        roundtrip(&built);
+    }
+}
+
+#[test]
+fn normalises() {
+    // Built predicates are the same as if they had been parsed
+    for (pred, text) in [
+        (P::Flag("unix"), "unix"),
+        (P::Flag("test"), "test"),
+        (
+            P::KeyValue {
+                key: "target_os",
+                val: "linux",
+            },
+            r#"target_os = "linux""#,
+        ),
+        (P::Flag("feature"), "feature"),
+    ] {
+        let built = Expression::try_from(pred).unwrap();
+        let parsed = Expression::parse_with_options(
+            text,
+            ParseOptions {
+                dialect: Dialect::Rustc,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(built, parsed);
+    }
+
+    // Names that can't be rendered as a single predicate are rejected
+    for (pred, reason) in [
+        (P::Flag(""), Reason::Empty),
+        (P::Flag("a = \"b\""), Reason::InvalidCharacters),
+        (P::Flag("not(a)"), Reason::InvalidCharacters),
+        (P::Flag("r#a"), Reason::InvalidCharacters),
+        (
+            P::KeyValue { key: "", val: "a" },
+            Reason::Unexpected(&["<key>", "all", "any", "not"]),
+        ),
+        (P::Feature("a\" , b = \""), Reason::MultipleRootPredicates),
+    ] {
+        assert_eq!(
+            Expression::try_from(pred.clone()).unwrap_err().reason,
+            reason,
+            "{pred:?}"
+        );
+    }
+}
+
+#[test]
+fn functions() {
+    let built = Expression::all([
+        P::Target(TP::Family(Family::unix)).try_into().unwrap(),
+        Expression::any([
+            P::Target(TP::Os(Os::linux)).try_into().unwrap(),
+            Expression::not(P::Target(TP::Arch(Arch::x86)).try_into().unwrap()),
+        ]),
+        Expression::any([]),
+        Expression::all([]),
+        Expression::not(Expression::all([P::Feature("nope").try_into().unwrap()])),
+    ]);
+
+    similar_asserts::assert_eq!(
+        built.original(),
+        r#"all(unix, any(target_os = "linux", not(target_arch = "x86")), any(), all(), not(all(feature = "nope")))"#
+    );
+    roundtrip(&built);
+
+    assert!(!built.eval(|pred| match pred {
+        P::Target(tp) =>
+            tp.matches(get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap()),
+        _ => false,
+    }));
+}
+
+#[test]
+fn canonicalizes_parsed() {
+    let parsed = Expression::parse(
+        r#"cfg(all(
+            unix,
+            target_family   =   "unix",
+            not(target_pointer_width="32",),
+            any(),
+        ))"#,
+    )
+    .unwrap();
+
+    let built = Expression::any([parsed.clone()]);
+
+    similar_asserts::assert_eq!(
+        built.original(),
+        r#"any(all(unix, unix, not(target_pointer_width = "32"), any()))"#
+    );
+    roundtrip(&built);
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let eval = |expr: &Expression| {
+        expr.eval(|pred| match pred {
+            P::Target(tp) => tp.matches(linux),
+            _ => false,
+        })
+    };
+
+    assert_eq!(eval(&parsed), eval(&built));
+}
@@ -0,0 +1,214 @@
+use cfg_expr::{
+    Expression,
+    error::Reason,
+    targets::{
+        CfgSet, HasAtomics, ReliableFloats, TargetInfo, TargetMetadata,
+        get_builtin_target_by_triple,
+    },
+};
+
+const LINUX_GNU: &str = r#"debug_assertions
+panic="unwind"
+target_abi=""
+target_arch="x86_64"
+target_endian="little"
+target_env="gnu"
+target_family="unix"
+target_feature="fxsr"
+target_feature="sse"
+target_feature="sse2"
+target_has_atomic="16"
+target_has_atomic="32"
+target_has_atomic="64"
+target_has_atomic="8"
+target_has_atomic="ptr"
+target_os="linux"
+target_pointer_width="64"
+target_vendor="unknown"
+unix
+"#;
+
+// RUSTC_BOOTSTRAP=1 rustc --print cfg --target thumbv7em-none-eabihf -C panic=abort --cfg 'feature="serde"' --cfg my_flag
+const THUMB: &str = r#"debug_assertions
+emscripten_wasm_eh
+feature="serde"
+fmt_debug="full"
+my_flag
+overflow_checks
+panic="abort"
+relocation_model="static"
+target_abi="eabihf"
+target_arch="arm"
+target_endian="little"
+target_env=""
+target_feature="dsp"
+target_feature="fpregs"
+target_feature="mclass"
+target_feature="thumb-mode"
+target_feature="thumb2"
+target_feature="v5te"
+target_feature="v6"
+target_feature="v6k"
+target_feature="v6t2"
+target_feature="v7"
+target_has_atomic
+target_has_atomic="16"
+target_has_atomic="32"
+target_has_atomic="8"
+target_has_atomic="ptr"
+target_has_atomic_equal_alignment="16"
+target_has_atomic_equal_alignment="32"
+target_has_atomic_equal_alignment="8"
+target_has_atomic_equal_alignment="ptr"
+target_has_atomic_load_store
+target_has_atomic_load_store="16"
+target_has_atomic_load_store="32"
+target_has_atomic_load_store="8"
+target_has_atomic_load_store="ptr"
+target_has_reliable_f128
+target_has_reliable_f16
+target_has_reliable_f16_math
+target_os="none"
+target_pointer_width="32"
+target_vendor="unknown"
+ub_checks
+"#;
+
+#[test]
+fn matches_builtin() {
+    let cfgs = CfgSet::from_print_cfg("x86_64-unknown-linux-gnu", LINUX_GNU).unwrap();
+
+    // The support metadata isn't part of the cfgs
+    assert_eq!(cfgs.target.metadata, TargetMetadata::UNKNOWN);
+    assert_eq!(
+        cfgs.target,
+        TargetInfo {
+            // Stable rustc doesn't print the unstable target cfgs
+            has_atomics_load_store: HasAtomics::new_const(&[]),
+            has_atomics_equal_alignment: HasAtomics::new_const(&[]),
+            thread_local: false,
+            reliable_floats: ReliableFloats::NONE,
+            metadata: TargetMetadata::UNKNOWN,
+            ..get_builtin_target_by_triple("x86_64-unknown-linux-gnu")
+                .unwrap()
+                .clone()
+        }
+    );
+    assert_eq!(
+        cfgs.target
+            .target_features
+            .iter()
+            .map(|feat| feat.as_str())
+            .collect::<Vec<_>>(),
+        ["fxsr", "sse", "sse2"]
+    );
+    assert!(cfgs.debug_assertions);
+    assert!(cfgs.flags.is_empty());
+    assert!(cfgs.key_values.is_empty());
+
+    // Windows line endings are fine as well
+    let crlf = CfgSet::from_print_cfg("x86_64-unknown-linux-gnu", &LINUX_GNU.replace('\n', "\r\n"))
+        .unwrap();
+    assert_eq!(crlf, cfgs);
+}
+
+#[test]
+fn captures_everything() {
+    let cfgs = CfgSet::from_print_cfg("thumbv7em-none-eabihf", THUMB).unwrap();
+
+    let builtin = get_builtin_target_by_triple("thumbv7em-none-eabihf").unwrap();
+    assert_eq!(cfgs.target.os, None);
+    assert_eq!(cfgs.target.env, None);
+    assert_eq!(cfgs.target.abi, builtin.abi);
+    assert_eq!(cfgs.target.has_atomics, builtin.has_atomics);
+    assert_eq!(
+        cfgs.target.has_atomics_load_store,
+        builtin.has_atomics_load_store
+    );
+    assert_eq!(
+        cfgs.target.has_atomics_equal_alignment,
+        builtin.has_atomics_equal_alignment
+    );
+    assert_eq!(cfgs.target.reliable_floats, builtin.reliable_floats);
+    assert!(!cfgs.target.thread_local);
+    // The builtins only record stable target features, while this output was
+    // printed with RUSTC_BOOTSTRAP which includes the unstable ones as well
+    assert!(builtin.target_features.is_empty());
+    assert_eq!(cfgs.target.target_features.len(), 10);
+    assert!(cfgs.target.target_features.contains("thumb-mode"));
+    assert_eq!(cfgs.target.panic.as_str(), "abort");
+
+    assert_eq!(
+        cfgs.flags,
+        [
+            "emscripten_wasm_eh",
+            "my_flag",
+            "overflow_checks",
+            "target_has_atomic",
+            "target_has_atomic_load_store",
+            "ub_checks",
+        ]
+    );
+    assert_eq!(
+        cfgs.key_values,
+        [
+            ("feature".to_owned(), "serde".to_owned()),
+            ("fmt_debug".to_owned(), "full".to_owned()),
+            ("relocation_model".to_owned(), "static".to_owned()),
+        ]
+    );
+
+    let eval = |s: &str| cfgs.eval(&Expression::parse(s).unwrap());
+
+    assert_eq!(
+        eval(r#"all(target_os = "none", panic = "abort", target_feature = "thumb2")"#),
+        Some(true)
+    );
+    assert_eq!(eval(r#"any(unix, target_feature = "sse2")"#), Some(false));
+    assert_eq!(
+        eval(r#"all(feature = "serde", not(feature = "std"), my_flag, r#overflow_checks)"#),
+        Some(true)
+    );
+    assert_eq!(
+        eval(r#"all(relocation_model = "static", debug_assertions, not(test))"#),
+        Some(true)
+    );
+    assert_eq!(
+        eval(
+            r#"all(target_has_atomic_load_store = "ptr", target_has_reliable_f16, not(target_thread_local))"#
+        ),
+        Some(true)
+    );
+    assert_eq!(
+        eval(r#"all(fmt_debug = "full", ub_checks, not(miri))"#),
+        Some(true)
+    );
+    assert_eq!(eval(r#"sanitize = "address""#), Some(false));
+    assert_eq!(eval(r#"all(my_flag, version("1.80"))"#), None);
+    assert_eq!(eval(r#"all(not(my_flag), version("1.80"))"#), Some(false));
+}
+
+#[test]
+fn fails_invalid() {
+    let err = |output: &str| CfgSet::from_print_cfg("custom", output).unwrap_err();
+
+    let e = err("target_arch=\"x86_64\"\ntarget_endian=\"middle\"\n");
+    assert_eq!(e.span, 36..42);
+    assert!(matches!(e.reason, Reason::Unexpected(&["big", "little"])));
+
+    let e = err("target_arch=\"x86_64\"\ntarget_pointer_width=\"sixty-four\"");
+    assert_eq!(e.span, 43..53);
+    assert_eq!(e.reason, Reason::InvalidInteger);
+
+    let e = err("target_has_atomic=\"huge\"");
+    assert_eq!(e.span, 19..23);
+    assert_eq!(e.reason, Reason::InvalidHasAtomic);
+
+    let e = err("target_arch=\"x86_64\ntarget_endian=\"little\"");
+    assert_eq!(e.span, 12..19);
+    assert_eq!(e.reason, Reason::UnclosedQuotes);
+
+    let e = err("target_arch=\"x86_64\"\ntarget_endian=\"little\"\n");
+    assert_eq!(e.span, 44..44);
+    assert_eq!(e.reason, Reason::Unexpected(&["target_pointer_width"]));
+}
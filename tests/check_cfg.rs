@@ -0,0 +1,210 @@
+use cfg_expr::{
+    Expression,
+    error::Reason,
+    expr::{CheckCfg, ExpectedValues, UnexpectedCfg, UnexpectedKind},
+    targets::ALL_BUILTINS,
+};
+
+fn check(check: &CheckCfg, expr: &str) -> Vec<(UnexpectedKind, String)> {
+    let expr = Expression::parse(expr).unwrap();
+    check
+        .check(&expr)
+        .into_iter()
+        .map(|u| (u.kind, expr.original()[u.span].to_owned()))
+        .collect()
+}
+
+#[test]
+fn well_known() {
+    let wk = CheckCfg::well_known(ALL_BUILTINS);
+
+    for expected in [
+        r#"all(unix, target_os = "linux", target_arch = "x86_64", target_pointer_width = "64")"#,
+        r#"all(target_os = "none", target_env = "", target_abi = "", target_vendor = "unknown")"#,
+        r#"any(panic = "abort", panic = "immediate-abort", target_family = "wasm")"#,
+        r#"all(target_has_atomic = "ptr", target_has_atomic_load_store = "128", target_has_atomic_equal_alignment = "8")"#,
+        r#"all(target_feature = "anything", target_thread_local, target_has_reliable_f16)"#,
+        r#"all(debug_assertions, overflow_checks, ub_checks, doc, doctest, miri, clippy)"#,
+        r#"any(fmt_debug = "shallow", relocation_model = "ropi-rwpi", sanitize = "kcfi")"#,
+        // Neither literals nor versions are cfgs
+        r#"all(true, not(false), version("1.80"))"#,
+    ] {
+        assert!(check(&wk, expected).is_empty(), "{expected}");
+    }
+
+    // Cargo passes these, but rustc doesn't know them
+    assert_eq!(
+        check(&wk, r#"all(test, docsrs, feature = "std")"#),
+        [
+            (UnexpectedKind::Name, "test".to_owned()),
+            (UnexpectedKind::Name, "docsrs".to_owned()),
+            (UnexpectedKind::Name, "feature".to_owned()),
+        ]
+    );
+
+    assert_eq!(
+        check(
+            &wk,
+            r#"any(target_os = "linx", clippy = "yes", target_pointer_width = "128")"#
+        ),
+        [
+            (UnexpectedKind::Value, "linx".to_owned()),
+            (UnexpectedKind::Value, "yes".to_owned()),
+            (UnexpectedKind::Value, "128".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn specs() {
+    let mut cc = CheckCfg::default();
+    cc.add_spec(r#"cfg(feature, values("std", "alloc"))"#)
+        .unwrap();
+    cc.add_spec(r#"cfg(feature, values("serde"))"#).unwrap();
+    cc.add_spec("cfg(my_flag, r#true)").unwrap();
+    cc.add_spec(r#"cfg(either, values(none(), "a"))"#).unwrap();
+    cc.add_spec("cfg(anything, values(any()))").unwrap();
+    cc.add_spec("cfg(nothing, values())").unwrap();
+    cc.add_spec("cfg()").unwrap();
+
+    assert_eq!(
+        cc.names["feature"],
+        ExpectedValues {
+            none: false,
+            any: false,
+            values: ["alloc", "serde", "std"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    );
+    assert!(!cc.any_name);
+
+    assert!(
+        check(
+            &cc,
+            r#"all(feature = "std", feature = "serde", r#my_flag, r#true, either, either = "a", anything, anything = "b")"#
+        )
+        .is_empty()
+    );
+
+    let expr = Expression::parse(
+        r#"all(feature = "stq", my_flag = "a", either = "b", nothing, nothing = "")"#,
+    )
+    .unwrap();
+    let unexpected = cc.check(&expr);
+    assert_eq!(
+        unexpected[0],
+        UnexpectedCfg {
+            name: "feature".to_owned(),
+            value: Some("stq".to_owned()),
+            kind: UnexpectedKind::Value,
+            span: 15..18,
+            suggestion: Some("std".to_owned()),
+        }
+    );
+    assert_eq!(
+        unexpected
+            .iter()
+            .map(|u| (u.kind, &expr.original()[u.span.clone()]))
+            .collect::<Vec<_>>(),
+        [
+            (UnexpectedKind::Value, "stq"),
+            (UnexpectedKind::Value, "a"),
+            (UnexpectedKind::Value, "b"),
+            // Without a value, the span is that of the name
+            (UnexpectedKind::Value, "nothing"),
+            (UnexpectedKind::Value, ""),
+        ]
+    );
+
+    assert_eq!(
+        check(&cc, "other"),
+        [(UnexpectedKind::Name, "other".to_owned())]
+    );
+    cc.add_spec("cfg(any())").unwrap();
+    assert!(check(&cc, "other").is_empty());
+
+    // Merging is the same as adding each spec
+    let mut merged = CheckCfg::well_known(ALL_BUILTINS);
+    merged.merge(&cc);
+    assert!(merged.any_name);
+    assert!(merged.names["feature"].values.contains("serde"));
+    assert!(merged.names["unix"].none);
+}
+
+#[test]
+fn fails_invalid() {
+    let err = |spec: &str| CheckCfg::default().add_spec(spec).unwrap_err();
+
+    let e = err("names(foo)");
+    assert_eq!(e.span, 0..10);
+    assert_eq!(e.reason, Reason::Unexpected(&["cfg("]));
+
+    let e = err("cfg(foo");
+    assert_eq!(e.span, 3..7);
+    assert_eq!(e.reason, Reason::UnclosedParens);
+
+    let e = err(r#"cfg(foo, values("a"), bar)"#);
+    assert_eq!(e.span, 22..25);
+    assert_eq!(e.reason, Reason::Unexpected(&[")"]));
+
+    let e = err(r#"cfg(foo values("a"))"#);
+    assert_eq!(e.span, 8..14);
+    assert_eq!(e.reason, Reason::Unexpected(&[",", ")"]));
+
+    let e = err(r#"cfg(foo, values("a")"#);
+    assert_eq!(e.span, 19..19);
+    assert_eq!(e.reason, Reason::UnclosedParens);
+
+    let e = err("cfg(foo, values(bar))");
+    assert_eq!(e.span, 16..19);
+    assert_eq!(
+        e.reason,
+        Reason::Unexpected(&["\"<value>\"", "none", "any"])
+    );
+
+    let e = err("cfg(foo, values(none))");
+    assert_eq!(e.span, 20..21);
+    assert_eq!(e.reason, Reason::Unexpected(&["("]));
+
+    let e = err(r#"cfg(foo = "a")"#);
+    assert_eq!(e.span, 8..9);
+    assert_eq!(e.reason, Reason::Unexpected(&[",", ")"]));
+
+    let e = err(r#"cfg(foo, values("a))"#);
+    assert_eq!(e.span, 16..19);
+    assert_eq!(e.reason, Reason::UnclosedQuotes);
+}
+
+#[test]
+fn suggestions() {
+    let wk = CheckCfg::well_known(ALL_BUILTINS);
+
+    let expr =
+        Expression::parse("any(\n    target_os = \"macosx\",\n    clipy,\n    xyzzy\n)").unwrap();
+    let unexpected = wk.check(&expr);
+
+    assert_eq!(
+        unexpected
+            .iter()
+            .map(|u| u.suggestion.as_deref())
+            .collect::<Vec<_>>(),
+        [Some("macos"), Some("clippy"), None]
+    );
+
+    assert_eq!(
+        unexpected[0].diagnostic(&expr).to_string(),
+        r#"error: unexpected value `"macosx"` for `target_os`
+ --> 2:18
+  |
+2 |     target_os = "macosx",
+  |     --------- for this key
+  |                  ^^^^^^ did you mean `macos`?
+"#
+    );
+    assert_eq!(
+        unexpected[2].diagnostic(&expr).to_string(),
+        "error: unexpected cfg name `xyzzy`\n --> 4:5\n  |\n4 |     xyzzy\n  |     ^^^^^\n"
+    );
+}
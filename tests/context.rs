@@ -0,0 +1,116 @@
+use cfg_expr::{
+    Expression,
+    expr::{CompilationContext, FmtDebug, Profile, RelocationModel, RustcVersion, Sanitizer},
+    targets::{CfgSet, get_builtin_target_by_triple},
+};
+
+#[test]
+fn evaluates_everything() {
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+
+    let mut ctx = CompilationContext::new(linux);
+    ctx.target_features = Some(vec!["sse2".to_owned(), "avx2".to_owned()]);
+    ctx.features = Some(vec!["std".to_owned()]);
+    ctx.profile = Profile {
+        test: Some(true),
+        sanitizers: Some(vec![Sanitizer::Address]),
+        relocation_model: Some(RelocationModel::Pic),
+        ..Profile::release()
+    };
+    ctx.rustc_version = Some(RustcVersion {
+        major: 1,
+        minor: 85,
+        patch: 0,
+    });
+    ctx.cfgs = vec![
+        ("my_flag".to_owned(), None),
+        ("my_key".to_owned(), Some("value".to_owned())),
+    ];
+
+    let eval = |s: &str| -> bool { Expression::parse(s).unwrap().eval_in(&ctx) };
+
+    assert!(eval(
+        r#"all(target_os = "linux", target_feature = "avx2", not(target_feature = "avx512f"))"#
+    ));
+    assert!(eval(r#"all(feature = "std", not(feature = "alloc"))"#));
+    assert!(eval(
+        "all(test, not(debug_assertions), not(overflow_checks), not(proc_macro), not(miri))"
+    ));
+    assert!(eval(
+        r#"all(sanitize = "address", not(sanitize = "thread"), fmt_debug = "full", relocation_model = "pic")"#
+    ));
+    assert!(eval(r#"all(version("1.85"), not(version("1.86")))"#));
+    assert!(eval(
+        r#"all(my_flag, r#my_flag, my_key = "value", not(my_key = "other"), not(other_flag))"#
+    ));
+}
+
+#[test]
+fn unknown_parts() {
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+
+    let mut ctx = CompilationContext::new(linux);
+    ctx.target_features = None;
+    ctx.features = None;
+    ctx.profile = Profile {
+        fmt_debug: Some(FmtDebug::Shallow),
+        ..Profile::default()
+    };
+    ctx.cfgs = vec![("my_flag".to_owned(), None)];
+    ctx.unknown_cfgs = true;
+
+    let eval = |s: &str| -> Option<bool> { Expression::parse(s).unwrap().eval_in(&ctx) };
+
+    for unknown in [
+        r#"target_feature = "sse2""#,
+        r#"feature = "std""#,
+        "debug_assertions",
+        "test",
+        r#"sanitize = "address""#,
+        r#"relocation_model = "pic""#,
+        r#"version("1.80")"#,
+        "other_flag",
+        r#"all(unix, not(feature = "std"))"#,
+    ] {
+        assert_eq!(eval(unknown), None, "{unknown}");
+
+        // An unknown result is false when evaluating to a bool
+        let enabled: bool = Expression::parse(unknown).unwrap().eval_in(&ctx);
+        assert!(!enabled, "{unknown}");
+    }
+
+    // The result is only unknown if it depends on the unknown parts
+    assert_eq!(eval(r#"any(unix, feature = "std")"#), Some(true));
+    assert_eq!(eval(r#"all(windows, feature = "std")"#), Some(false));
+    assert_eq!(eval(r#"all(fmt_debug = "shallow", my_flag)"#), Some(true));
+}
+
+#[test]
+fn any_target_matcher() {
+    let cfgs = CfgSet::from_print_cfg(
+        "custom",
+        "target_arch=\"arm\"\ntarget_endian=\"big\"\ntarget_pointer_width=\"32\"\n",
+    )
+    .unwrap();
+    let expr =
+        Expression::parse(r#"all(target_arch = "arm", target_endian = "big", test)"#).unwrap();
+
+    let mut ctx = CompilationContext::new(&cfgs);
+    assert!(!expr.eval_in::<_, bool>(&ctx));
+    ctx.profile.test = Some(true);
+    assert!(expr.eval_in::<_, bool>(&ctx));
+}
+
+#[cfg(feature = "targets")]
+#[test]
+fn lexicon_triple() {
+    let expr =
+        Expression::parse(r#"all(target_arch = "arm", target_endian = "big", test)"#).unwrap();
+
+    let mut ctx = CompilationContext::new(cfg_expr::target_lexicon::Triple::host());
+    ctx.profile.test = Some(true);
+    assert_eq!(
+        expr.eval_in::<_, Option<bool>>(&ctx),
+        Some(cfg!(all(target_arch = "arm", target_endian = "big")))
+    );
+}
@@ -0,0 +1,232 @@
+use cfg_expr::{
+    Expression, Predicate,
+    error::Reason,
+    expr::{Dialect, ParseOptions},
+};
+
+fn parse(dialect: Dialect, s: &str) -> Result<Expression, cfg_expr::ParseError> {
+    Expression::parse_with_options(
+        s,
+        ParseOptions {
+            dialect,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn default_is_strict() {
+    assert_eq!(ParseOptions::default().dialect, Dialect::Strict);
+
+    for valid in [
+        r#"cfg(all(unix, not(target_os = "macos"), feature = "std"))"#,
+        r#"any(version("1.80"), r#true, target_pointer_width = "64")"#,
+        "all(unix,)",
+        "unix,",
+    ] {
+        assert_eq!(
+            parse(Dialect::Strict, valid).unwrap(),
+            Expression::parse(valid).unwrap()
+        );
+    }
+}
+
+/// Predicates that can never be true are only rejected by this crate
+#[test]
+fn any_name_and_value() {
+    for (expr, strict) in [
+        ("feature", Reason::Unexpected(&["= \"<feature_name>\""])),
+        (r#"unix = "yes""#, Reason::Unexpected(&[])),
+        (r#"test = "x""#, Reason::Unexpected(&[])),
+        (
+            "target_os",
+            Reason::Unexpected(&["= \"<target_cfg_value>\""]),
+        ),
+        ("panic", Reason::Unexpected(&["= \"<panic_strategy>\""])),
+        (r#"target_pointer_width = "x""#, Reason::InvalidInteger),
+        (r#"target_endian = "middle""#, Reason::InvalidInteger),
+        (r#"target_feature = """#, Reason::Unexpected(&["<feature>"])),
+    ] {
+        assert_eq!(parse(Dialect::Strict, expr).unwrap_err().reason, strict);
+
+        for dialect in [Dialect::Rustc, Dialect::Cargo] {
+            let parsed = parse(dialect, expr).unwrap();
+            assert!(
+                matches!(
+                    parsed.predicates().next().unwrap(),
+                    Predicate::Flag(_) | Predicate::KeyValue { .. }
+                ),
+                "{expr} {dialect:?}"
+            );
+        }
+    }
+
+    let expr = parse(Dialect::Rustc, r#"all(unix = "yes", feature, unix)"#).unwrap();
+    assert_eq!(
+        expr.predicates().collect::<Vec<_>>(),
+        [
+            Predicate::KeyValue {
+                key: "unix",
+                val: "yes"
+            },
+            Predicate::Flag("feature"),
+            Predicate::Target(cfg_expr::TargetPredicate::Family(
+                cfg_expr::targets::Family::unix
+            )),
+        ]
+    );
+
+    // The literals are never names
+    for dialect in [Dialect::Rustc, Dialect::Cargo] {
+        assert!(parse(dialect, r#"true = "x""#).is_err());
+    }
+
+    // Opting in to strict values still rejects unknown builtin values
+    let err = Expression::parse_with_options(
+        r#"target_os = "linuxx""#,
+        ParseOptions {
+            dialect: Dialect::Rustc,
+            strict: true,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.reason, Reason::UnknownBuiltin);
+}
+
+#[test]
+fn whitespace() {
+    for (expr, strict, rustc, cargo) in [
+        ("all( unix ,  windows )", true, true, true),
+        ("all(\tunix)", true, true, false),
+        ("any(\n  unix,\n  windows\n)", true, true, false),
+        ("all(unix\u{200E})", false, true, false),
+        ("all(unix\u{A0})", true, false, false),
+        ("all(unix\u{3000})", true, false, false),
+    ] {
+        assert_eq!(parse(Dialect::Strict, expr).is_ok(), strict, "{expr:?}");
+        assert_eq!(parse(Dialect::Rustc, expr).is_ok(), rustc, "{expr:?}");
+        assert_eq!(parse(Dialect::Cargo, expr).is_ok(), cargo, "{expr:?}");
+    }
+
+    let err = parse(Dialect::Cargo, "all(unix,\twindows)").unwrap_err();
+    assert_eq!(err.span, 9..10);
+}
+
+#[test]
+fn trailing_commas() {
+    for (expr, strict, rustc, cargo) in [
+        ("all(unix,)", true, true, true),
+        ("any(unix, windows,)", true, true, true),
+        ("not(unix,)", true, true, false),
+        ("unix,", true, true, false),
+        // Only this crate ignores repeated commas
+        ("all(unix,,)", true, false, false),
+        ("all(,)", false, false, false),
+    ] {
+        assert_eq!(parse(Dialect::Strict, expr).is_ok(), strict, "{expr}");
+        assert_eq!(parse(Dialect::Rustc, expr).is_ok(), rustc, "{expr}");
+        assert_eq!(parse(Dialect::Cargo, expr).is_ok(), cargo, "{expr}");
+    }
+
+    let err = parse(Dialect::Cargo, "not(unix,)").unwrap_err();
+    assert_eq!(err.span, 8..9);
+    assert_eq!(err.reason, Reason::Unexpected(&[")"]));
+
+    let err = parse(Dialect::Cargo, "unix ,").unwrap_err();
+    assert_eq!(err.span, 5..6);
+}
+
+#[test]
+fn version() {
+    let expr = r#"version("1.80")"#;
+
+    assert!(parse(Dialect::Strict, expr).is_ok());
+    assert!(parse(Dialect::Rustc, expr).is_ok());
+
+    // Cargo has no `version()`, so it's just a name followed by a paren
+    let err = parse(Dialect::Cargo, expr).unwrap_err();
+    assert_eq!(err.span, 7..8);
+
+    assert_eq!(
+        parse(Dialect::Cargo, "version")
+            .unwrap()
+            .predicates()
+            .next(),
+        Some(Predicate::Flag("version"))
+    );
+}
+
+#[test]
+fn recovering() {
+    for dialect in [Dialect::Strict, Dialect::Rustc, Dialect::Cargo] {
+        let options = ParseOptions {
+            dialect,
+            ..Default::default()
+        };
+
+        for expr in [
+            "all(unix,,)",
+            "any(unix,, windows)",
+            "not(unix,)",
+            "unix,",
+            "all(unix,\twindows)",
+            r#"version("1.80")"#,
+            r#"all(unix = "yes", feature)"#,
+        ] {
+            let recovered = Expression::parse_recovering_with_options(expr, options);
+
+            match parse(dialect, expr) {
+                Ok(parsed) => {
+                    assert!(recovered.errors.is_empty(), "{expr:?} {dialect:?}");
+                    assert_eq!(recovered.expr.unwrap(), parsed, "{expr:?} {dialect:?}");
+                }
+                Err(err) => {
+                    assert_eq!(recovered.errors[0].span, err.span, "{expr:?} {dialect:?}");
+                }
+            }
+        }
+    }
+
+    // Every recovered span points into the string stored in its error, even
+    // around characters that are only whitespace in some dialects
+    for expr in [
+        "\u{85}target_os=\"1.8a\"\né \"64\"target_os",
+        "all(unix,\u{A0}target_os = \"linuxx\", \u{85}é)",
+        "any(\u{3000}target_arch = \"x86-64\"\tnot(r#true = \"é\")",
+        "cfg(all(é, version(\"1.8é\"), panic = \"unwnd\"",
+    ] {
+        for dialect in [Dialect::Strict, Dialect::Rustc, Dialect::Cargo] {
+            for strict in [false, true] {
+                let options = ParseOptions { dialect, strict };
+                let recovered = Expression::parse_recovering_with_options(expr, options);
+                assert!(!recovered.errors.is_empty(), "{expr:?} {options:?}");
+
+                for err in recovered.errors {
+                    assert!(
+                        err.original.get(err.span.clone()).is_some(),
+                        "{expr:?} {options:?} {err:?}"
+                    );
+                    let _ = err.diagnostic().to_string();
+                }
+            }
+        }
+    }
+
+    let options = ParseOptions {
+        dialect: Dialect::Rustc,
+        ..Default::default()
+    };
+    let recovered = Expression::parse_recovering_with_options("all(unix,,, windows", options);
+    assert_eq!(
+        recovered
+            .errors
+            .iter()
+            .map(|err| (err.span.clone(), &err.reason))
+            .collect::<Vec<_>>(),
+        [
+            (3..19, &Reason::UnclosedParens),
+            (9..10, &Reason::Unexpected(&[")", "<key>"])),
+            (10..11, &Reason::Unexpected(&[")", "<key>"])),
+        ]
+    );
+}
@@ -280,7 +280,13 @@ fn unstable_target_abi() {
         pointer_width: 64,
         endian: cfg_expr::targets::Endian::little,
         has_atomics: cfg_expr::targets::HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: cfg_expr::targets::HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: cfg_expr::targets::HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: cfg_expr::targets::Panic::unwind,
+        target_features: cfg_expr::targets::TargetFeatures::new_const(&[]),
+        thread_local: true,
+        reliable_floats: cfg_expr::targets::ReliableFloats::NONE,
+        metadata: cfg_expr::targets::TargetMetadata::UNKNOWN,
     };
 
     let target_with_abi_that_doesnt_match = cfg_expr::targets::TargetInfo {
@@ -388,3 +394,276 @@ fn features() {
         "all() with Some(true) and None evaluates to None"
     );
 }
+
+#[test]
+fn matching_builtins() {
+    use cfg_expr::expr::{BuiltinMatch, OwnedPredicate};
+
+    let matches = |s: &str| -> Vec<BuiltinMatch> {
+        Expression::parse(s).unwrap().matching_builtins().collect()
+    };
+
+    // Purely target predicates never depend on anything
+    let linux = matches(r#"all(target_os = "linux", target_arch = "x86_64", target_env = "gnu")"#);
+    assert!(
+        linux
+            .iter()
+            .any(|m| m.target.triple.as_str() == "x86_64-unknown-linux-gnu")
+    );
+    assert!(linux.iter().all(|m| m.depends_on.is_empty()));
+
+    // Contradictory target predicates match nothing
+    assert!(matches(r#"all(target_os = "linux", target_os = "windows")"#).is_empty());
+
+    // Non-target predicates are only reported for targets where they matter
+    let feat = matches(r#"any(windows, feature = "x")"#);
+    assert_eq!(feat.len(), all.len());
+    for m in feat {
+        if m.target
+            .families
+            .contains(&cfg_expr::targets::Family::windows)
+        {
+            assert!(m.depends_on.is_empty(), "{}", m.target.triple);
+        } else {
+            assert_eq!(
+                m.depends_on,
+                [OwnedPredicate::Feature("x".to_owned())],
+                "{}",
+                m.target.triple
+            );
+        }
+    }
+
+    // Predicates that can't change the result are not reported
+    let nested = matches(r#"all(unix, any(target_feature = "sse2", all(windows, feature = "a")))"#);
+    assert!(!nested.is_empty());
+    for m in nested {
+        assert!(m.target.families.contains(&cfg_expr::targets::Family::unix));
+        assert_eq!(
+            m.depends_on,
+            [OwnedPredicate::TargetFeature("sse2".to_owned())]
+        );
+    }
+
+    // A predicate that is irrelevant in the abstract is never reported
+    let irrelevant = matches(r#"any(unix, all(feature = "a", not(feature = "a")))"#);
+    assert!(irrelevant.iter().all(|m| m.depends_on.is_empty()));
+}
+
+#[test]
+fn triviality() {
+    use cfg_expr::expr::Triviality;
+
+    let trivial = |s: &str| -> Option<(Triviality, Vec<String>)> {
+        let expr = Expression::parse(s).unwrap();
+        expr.triviality().map(|t| {
+            (
+                t.triviality,
+                t.spans
+                    .into_iter()
+                    .map(|span| expr.original()[span].to_owned())
+                    .collect(),
+            )
+        })
+    };
+
+    assert_eq!(
+        trivial(r#"all(target_os = "linux", target_os = "windows")"#),
+        Some((
+            Triviality::NeverTrue,
+            vec![
+                r#"target_os = "linux""#.to_owned(),
+                r#"target_os = "windows""#.to_owned()
+            ]
+        ))
+    );
+
+    assert_eq!(
+        trivial(r#"all(unix, feature = "a", target_pointer_width = "48")"#),
+        Some((
+            Triviality::NeverTrue,
+            vec![r#"target_pointer_width = "48""#.to_owned()]
+        ))
+    );
+
+    assert_eq!(
+        trivial(r#"any(target_endian = "little", target_endian = "big")"#),
+        Some((
+            Triviality::AlwaysTrue,
+            vec![
+                r#"target_endian = "little""#.to_owned(),
+                r#"target_endian = "big""#.to_owned()
+            ]
+        ))
+    );
+
+    assert_eq!(
+        trivial(r#"not(target_os = "made_up")"#),
+        Some((
+            Triviality::AlwaysTrue,
+            vec![r#"target_os = "made_up""#.to_owned()]
+        ))
+    );
+
+    // Trivial regardless of the target
+    assert_eq!(
+        trivial(r#"any(feature = "a", not(feature = "a"))"#),
+        Some((
+            Triviality::AlwaysTrue,
+            vec![r#"any(feature = "a", not(feature = "a"))"#.to_owned()]
+        ))
+    );
+
+    assert_eq!(trivial(r#"all(unix, target_arch = "x86_64")"#), None);
+    assert_eq!(trivial(r#"any(windows, feature = "a")"#), None);
+}
+
+#[test]
+fn literals() {
+    let eval = |s: &str| -> bool {
+        Expression::parse(s)
+            .unwrap()
+            .eval(|pred| panic!("unexpected predicate {pred:?}"))
+    };
+
+    assert!(eval("true"));
+    assert!(!eval("false"));
+    assert!(eval("cfg(true)"));
+    assert!(eval("not(false)"));
+    assert!(!eval("all(true, false)"));
+    assert!(eval("any(false, true)"));
+
+    let expr = Expression::parse("any(false, unix)").unwrap();
+    assert_eq!(
+        expr.eval(|_| Option::<bool>::None),
+        None,
+        "false is the identity for any()"
+    );
+    assert!(
+        Expression::parse("any(true, unix)")
+            .unwrap()
+            .eval(|_| Option::<bool>::None)
+            == Some(true)
+    );
+}
+
+#[test]
+fn version() {
+    use cfg_expr::expr::RustcVersion;
+
+    let expr = Expression::parse(r#"all(version("1.80"), not(version("1.90.1")))"#).unwrap();
+
+    let eval = |rustc: &str| {
+        let rustc: RustcVersion = rustc.parse().unwrap();
+        expr.eval(|pred| match pred {
+            Predicate::Version { version, .. } => version.matches(&rustc),
+            _ => panic!("unexpected predicate {pred:?}"),
+        })
+    };
+
+    assert!(!eval("1.79.99"));
+    assert!(eval("1.80.0"));
+    assert!(eval("1.90.0"));
+    assert!(!eval("1.90.1"));
+    assert!(!eval("2.0"));
+
+    // The default is the version the builtin targets were retrieved from
+    assert_eq!(
+        RustcVersion::default().to_string(),
+        cfg_expr::targets::rustc_version()
+    );
+    assert!(
+        Expression::parse(r#"version("1.0")"#)
+            .unwrap()
+            .eval(|pred| match pred {
+                Predicate::Version { version, .. } => version.matches(&RustcVersion::default()),
+                _ => false,
+            })
+    );
+}
+
+#[test]
+fn builtin_target_features() {
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let apple = get_builtin_target_by_triple("aarch64-apple-darwin").unwrap();
+
+    let expr =
+        Expression::parse(r#"all(target_feature = "sse2", not(target_feature = "avx"))"#).unwrap();
+
+    let eval = |target: &cfg_expr::targets::TargetInfo| {
+        expr.eval(|pred| match pred {
+            Predicate::TargetFeature(feat) => target.target_features.contains(feat),
+            _ => panic!("unexpected predicate {pred:?}"),
+        })
+    };
+
+    assert!(eval(linux));
+    assert!(!eval(apple));
+    assert!(apple.target_features.contains("neon"));
+
+    // Each builtin's features are sorted so they can be searched
+    for target in all {
+        assert!(
+            target
+                .target_features
+                .windows(2)
+                .all(|w| w[0].as_str() < w[1].as_str()),
+            "{}",
+            target.triple
+        );
+    }
+}
+
+#[test]
+fn builtin_metadata() {
+    use cfg_expr::targets::{builtins_with_host_tools, builtins_with_std, builtins_with_tier};
+
+    let tier1: Vec<_> = builtins_with_tier(1).map(|ti| ti.triple.as_str()).collect();
+    assert!(tier1.contains(&"x86_64-unknown-linux-gnu"));
+    assert!(tier1.contains(&"x86_64-pc-windows-msvc"));
+    assert!(!tier1.contains(&"thumbv7em-none-eabihf"));
+
+    // Every tier 1 target is a full host platform
+    for target in builtins_with_tier(1) {
+        assert_eq!(target.metadata.std, Some(true), "{}", target.triple);
+        assert_eq!(target.metadata.host_tools, Some(true), "{}", target.triple);
+    }
+
+    assert!(builtins_with_host_tools().all(|ti| ti.metadata.tier.is_some()));
+    assert!(builtins_with_std().all(|ti| ti.triple.as_str() != "thumbv7em-none-eabihf"));
+    assert!(builtins_with_std().any(|ti| ti.triple.as_str() == "wasm32-unknown-unknown"));
+
+    assert_eq!(
+        builtins_with_tier(1).count()
+            + builtins_with_tier(2).count()
+            + builtins_with_tier(3).count(),
+        all.iter().filter(|ti| ti.metadata.tier.is_some()).count()
+    );
+}
+
+#[test]
+fn unstable_target_cfgs() {
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let thumb = get_builtin_target_by_triple("thumbv6m-none-eabi").unwrap();
+
+    let eval = |expr: &str, target: &cfg_expr::targets::TargetInfo| {
+        Expression::parse(expr).unwrap().eval(|pred| match pred {
+            Predicate::Target(tp) => tp.matches(target),
+            _ => false,
+        })
+    };
+
+    // thumbv6m can load and store atomics, but doesn't support compare and swap
+    let atomics = r#"all(target_has_atomic_load_store = "32", target_has_atomic = "32")"#;
+    assert!(eval(atomics, linux));
+    assert!(!eval(atomics, thumb));
+    assert!(eval(r#"target_has_atomic_load_store = "32""#, thumb));
+    assert!(eval(r#"target_has_atomic_equal_alignment = "ptr""#, thumb));
+
+    assert!(eval(
+        "all(target_thread_local, target_has_reliable_f16)",
+        linux
+    ));
+    assert!(!eval("target_thread_local", thumb));
+    assert!(!eval("target_has_reliable_f128_math", linux));
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "history")]
+
+use cfg_expr::{
+    expr::RustcVersion,
+    targets::{
+        ALL_BUILTINS, BUILTIN_HISTORY, HasAtomic, TargetChange, TargetHistory,
+        get_target_for_version, get_target_history, history_versions, rustc_version,
+    },
+};
+
+fn v(version: &str) -> RustcVersion {
+    version.parse().unwrap()
+}
+
+#[test]
+fn latest_matches_builtins() {
+    let latest = v(rustc_version());
+    assert_eq!(history_versions().end(), &latest);
+
+    for builtin in ALL_BUILTINS {
+        assert_eq!(
+            get_target_for_version(builtin.triple.as_str(), &latest),
+            Some(builtin)
+        );
+        // Releases after the recorded history use the latest information
+        assert_eq!(
+            get_target_for_version(builtin.triple.as_str(), &v("1.200")),
+            Some(builtin)
+        );
+    }
+}
+
+#[test]
+fn sorted_and_ordered() {
+    assert!(
+        BUILTIN_HISTORY
+            .windows(2)
+            .all(|pair| pair[0].triple < pair[1].triple)
+    );
+
+    for history in BUILTIN_HISTORY {
+        assert!(
+            history
+                .changes
+                .windows(2)
+                .all(|pair| pair[0].since < pair[1].since && pair[0].info != pair[1].info),
+            "{}",
+            history.triple
+        );
+        assert!(history.first_version() <= history.last_version());
+    }
+}
+
+#[test]
+fn removed_targets() {
+    let wasi = get_target_history("wasm32-wasi").unwrap();
+    assert_eq!(wasi.first_version(), v("1.60"));
+    assert_eq!(wasi.last_version(), v("1.83"));
+
+    assert!(get_target_for_version("wasm32-wasi", &v("1.83.0")).is_some());
+    assert!(get_target_for_version("wasm32-wasi", &v("1.84.0")).is_none());
+    assert!(cfg_expr::targets::get_builtin_target_by_triple("wasm32-wasi").is_none());
+
+    // Its replacement
+    let p1 = get_target_history("wasm32-wasip1").unwrap();
+    assert!(p1.first_version() > v("1.60"));
+    assert_eq!(p1.last_version(), *history_versions().end());
+
+    // The release before a new major version isn't known, so the last change
+    // while the target existed is used instead
+    let linux = get_target_for_version("x86_64-unknown-linux-gnu", &v("1.90")).unwrap();
+    let history = TargetHistory {
+        triple: linux.triple.clone(),
+        changes: Box::leak(Box::new([
+            TargetChange {
+                since: v("1.60"),
+                info: Some(linux.clone()),
+            },
+            TargetChange {
+                since: v("1.90"),
+                info: Some(linux.clone()),
+            },
+            TargetChange {
+                since: v("2.0"),
+                info: None,
+            },
+        ])),
+    };
+    assert_eq!(history.last_version(), v("1.90"));
+}
+
+#[test]
+fn changed_values() {
+    let catalyst =
+        |version: &str| get_target_for_version("x86_64-apple-ios-macabi", &v(version)).unwrap();
+
+    assert_eq!(catalyst("1.77.2").abi, None);
+    assert_eq!(catalyst("1.78.0").abi.as_ref().unwrap().as_str(), "macabi");
+    assert_eq!(catalyst("1.90.0").env, None);
+    assert_eq!(catalyst("1.91.0").env.as_ref().unwrap().as_str(), "macabi");
+
+    // Patch releases use the information of their minor release
+    assert_eq!(catalyst("1.78.1"), catalyst("1.78.0"));
+
+    let x86 =
+        |version: &str| get_target_for_version("x86_64-unknown-linux-gnu", &v(version)).unwrap();
+    assert!(x86("1.60").has_atomics.contains(HasAtomic::Pointer));
+    assert!(x86("1.60").target_features.contains("sse2"));
+
+    // The unstable target cfgs are recorded from the releases that have them
+    assert!(x86("1.60").thread_local);
+    assert!(
+        x86("1.60")
+            .has_atomics_load_store
+            .contains(HasAtomic::Pointer)
+    );
+    assert!(!x86("1.87").reliable_floats.f16);
+    assert!(x86("1.88").reliable_floats.f16);
+}
+
+#[test]
+fn outside_history() {
+    assert_eq!(history_versions().start(), &v("1.60.0"));
+
+    // The history doesn't go back further than 1.60
+    assert!(get_target_for_version("x86_64-unknown-linux-gnu", &v("1.59.0")).is_none());
+    assert!(get_target_for_version("x86_64-unknown-linux-gnu", &v("1.60.0")).is_some());
+
+    assert!(get_target_history("not-a-real-target").is_none());
+}
@@ -62,3 +62,52 @@ fn handle_cfg_keywords() {
         ]
     );
 }
+
+#[test]
+fn version() {
+    test_lex!(
+        r#"version("1.80")"#,
+        [
+            Token::Version,
+            Token::OpenParen,
+            Token::Value("1.80"),
+            Token::CloseParen,
+        ]
+    );
+    test_lex!(
+        r#"version ( "1.80" )"#,
+        [
+            Token::Version,
+            Token::OpenParen,
+            Token::Value("1.80"),
+            Token::CloseParen,
+        ]
+    );
+
+    // Without a paren it's just a normal key
+    test_lex!("version", [Token::Key("version")]);
+    test_lex!(
+        r#"version = "1""#,
+        [Token::Key("version"), Token::Equals, Token::Value("1")]
+    );
+}
+
+#[test]
+fn raw_identifiers() {
+    test_lex!("r#true", [Token::Key("r#true")]);
+    test_lex!(
+        r#"r#all(r#false = "x")"#,
+        [
+            Token::Key("r#all"),
+            Token::OpenParen,
+            Token::Key("r#false"),
+            Token::Equals,
+            Token::Value("x"),
+            Token::CloseParen,
+        ]
+    );
+    test_lex!("r", [Token::Key("r")]);
+
+    assert!(Lexer::new("r#").any(|lt| lt.is_err()));
+    assert!(Lexer::new("r#1").any(|lt| lt.is_err()));
+}
@@ -0,0 +1,107 @@
+use cfg_expr::Expression;
+
+fn ex(text: &str) -> Expression {
+    Expression::parse(text).unwrap()
+}
+
+#[test]
+fn implies() {
+    for (a, b) in [
+        ("unix", "unix"),
+        ("unix", "any(unix, windows)"),
+        ("all(unix, feature = \"a\")", "unix"),
+        ("any()", "unix"),
+        ("unix", "all()"),
+        ("not(any(a, b))", "not(a)"),
+        ("all(a, not(a))", "b"),
+        ("b", "any(a, not(a))"),
+    ] {
+        assert!(ex(a).implies(&ex(b)), "'{a}' should imply '{b}'");
+    }
+
+    for (a, b) in [
+        ("unix", "windows"),
+        ("any(unix, windows)", "unix"),
+        ("all()", "unix"),
+        ("unix", "any()"),
+        ("not(a)", "not(any(a, b))"),
+        // Each predicate is independent
+        (r#"target_os = "linux""#, r#"not(target_os = "windows")"#),
+    ] {
+        assert!(!ex(a).implies(&ex(b)), "'{a}' should not imply '{b}'");
+    }
+}
+
+#[test]
+fn equivalence() {
+    for (a, b) in [
+        ("unix", "target_family = \"unix\""),
+        ("not(not(a))", "a"),
+        ("not(any(a, b))", "all(not(a), not(b))"),
+        ("not(all(a, b))", "any(not(a), not(b))"),
+        ("all(a, any(b, c))", "any(all(a, b), all(a, c))"),
+        ("all(a, any(a, b))", "a"),
+        ("all(a, not(a))", "any()"),
+        ("any(a, not(a))", "not(any())"),
+    ] {
+        let (a, b) = (ex(a), ex(b));
+        assert!(a.is_equivalent(&b), "'{a}' should be equivalent to '{b}'");
+        assert!(b.is_equivalent(&a), "'{b}' should be equivalent to '{a}'");
+        assert!(a.simplify().is_equivalent(&b));
+    }
+
+    for (a, b) in [
+        ("a", "b"),
+        ("all(a, b)", "any(a, b)"),
+        ("not(a)", "a"),
+        ("all()", "any()"),
+    ] {
+        assert!(
+            !ex(a).is_equivalent(&ex(b)),
+            "'{a}' should not be equivalent to '{b}'"
+        );
+    }
+}
+
+#[test]
+fn many_predicates() {
+    // Large enough that the truth table isn't used
+    let a = ex("all(any(a, b, c, d, e, f, g, h), any(i, j, k, l, m, n, o, p), not(all(q, r)))");
+    let b = ex(
+        "all(not(all(not(a), not(b), not(c), not(d), not(e), not(f), not(g), not(h))), any(p, o, n, m, l, k, j, i), any(not(q), not(r)))",
+    );
+
+    assert!(a.is_equivalent(&b));
+    assert!(a.implies(&ex("any(a, b, c, d, e, f, g, h, s)")));
+    assert!(!a.implies(&ex("any(a, b, c, d, e, f, g, s)")));
+    assert!(!a.is_equivalent(&ex(
+        "all(any(a, b, c, d, e, f, g, h), any(i, j, k, l, m, n, o, p), not(q))"
+    )));
+}
+
+#[test]
+fn polarity() {
+    let list = |prefix: &str, count: usize| {
+        (0..count)
+            .map(|i| format!("{prefix}{i}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    // Predicates that only occur with one polarity are fixed up front, so
+    // this is quick despite being far too large for a truth table
+    let a = ex(&format!("all({}, any(q, r))", list("x", 100)));
+    assert!(a.implies(&ex(&format!("any(q, r, {})", list("y", 100)))));
+    assert!(!a.implies(&ex(&format!("any(q, {})", list("y", 100)))));
+    assert!(!a.implies(&ex(&format!("all(x0, x99, not(all({})))", list("y", 100)))));
+
+    // The polarity of a predicate is flipped by each enclosing not()
+    for (a, b) in [
+        ("all(a, not(not(b)))", "b"),
+        ("not(any(a, not(b)))", "all(not(a), b)"),
+        ("not(all(a, not(not(b))))", "any(not(a), not(b))"),
+    ] {
+        assert!(ex(a).implies(&ex(b)), "'{a}' should imply '{b}'");
+    }
+    assert!(!ex("not(not(a))").implies(&ex("not(a)")));
+}
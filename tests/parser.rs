@@ -1,7 +1,7 @@
 use cfg_expr::{
     Expression, ParseError,
     error::Reason,
-    expr::{Predicate as P, TargetPredicate as TP},
+    expr::{ParseOptions, Predicate as P, TargetPredicate as TP},
     targets::*,
 };
 
@@ -164,3 +164,544 @@ fn ensures_not_has_one_predicate() {
         "not(not(not(key = \"value\",)))" => [P::KeyValue { key: "key", val: "value" }],
     ]);
 }
+
+#[test]
+fn predicates_in_source_order() {
+    test_validate!(ok [
+        "all(a, not(b), any(c, d), e)" => [P::Flag("a"), P::Flag("b"), P::Flag("c"), P::Flag("d"), P::Flag("e")],
+        "any(all(), a, all(b), c)" => [P::Flag("a"), P::Flag("b"), P::Flag("c")],
+    ]);
+}
+
+#[test]
+fn predicates_with_spans() {
+    use cfg_expr::expr::{Func, RustcVersion};
+
+    let expr = Expression::parse(
+        r#"all(r#a, not(any(true, b = "x", version("1.80"))), any(), c  =  "" , not(d))"#,
+    )
+    .unwrap();
+
+    let spanned: Vec<_> = expr
+        .predicates_with_spans()
+        .map(|sp| {
+            let text = |span: std::ops::Range<usize>| &expr.original()[span];
+            (
+                sp.predicate,
+                text(sp.span),
+                text(sp.key),
+                sp.value.map(text),
+                sp.path,
+            )
+        })
+        .collect();
+
+    let version = RustcVersion {
+        major: 1,
+        minor: 80,
+        patch: 0,
+    };
+
+    similar_asserts::assert_eq!(
+        spanned,
+        [
+            (P::Flag("a"), "r#a", "r#a", None, vec![Func::All(5)]),
+            (
+                P::KeyValue { key: "b", val: "x" },
+                r#"b = "x""#,
+                "b",
+                Some("x"),
+                vec![Func::Any(3), Func::Not, Func::All(5)]
+            ),
+            (
+                P::Version {
+                    version,
+                    span: 41..45
+                },
+                r#"version("1.80")"#,
+                "version",
+                Some("1.80"),
+                vec![Func::Any(3), Func::Not, Func::All(5)]
+            ),
+            (
+                P::KeyValue { key: "c", val: "" },
+                r#"c  =  """#,
+                "c",
+                Some(""),
+                vec![Func::All(5)]
+            ),
+            (P::Flag("d"), "d", "d", None, vec![Func::Not, Func::All(5)]),
+        ]
+    );
+
+    // A single predicate isn't inside any function
+    let expr = Expression::parse("cfg(unix)").unwrap();
+    let unix = expr.predicates_with_spans().next().unwrap();
+    assert_eq!(unix.span, 0..4);
+    assert!(unix.path.is_empty());
+
+    // Built expressions have the spans of their canonical rendering
+    let expr = Expression::not(Expression::any([
+        Expression::parse(r#"feature  =  "std""#).unwrap(),
+        P::Version {
+            version,
+            span: 0..0,
+        }
+        .try_into()
+        .unwrap(),
+    ]));
+    assert_eq!(
+        expr.original(),
+        r#"not(any(feature = "std", version("1.80.0")))"#
+    );
+
+    let spanned: Vec<_> = expr
+        .predicates_with_spans()
+        .map(|sp| {
+            let text = |span: std::ops::Range<usize>| &expr.original()[span];
+            (text(sp.span), text(sp.key), sp.value.map(text), sp.path)
+        })
+        .collect();
+    assert_eq!(
+        spanned,
+        [
+            (
+                r#"feature = "std""#,
+                "feature",
+                Some("std"),
+                vec![Func::Any(2), Func::Not]
+            ),
+            (
+                r#"version("1.80.0")"#,
+                "version",
+                Some("1.80.0"),
+                vec![Func::Any(2), Func::Not]
+            ),
+        ]
+    );
+
+    // Predicates kept as is by the other dialects have the same spans
+    let expr = Expression::parse_with_options(
+        r#"any(unix = "yes", feature)"#,
+        cfg_expr::expr::ParseOptions {
+            dialect: cfg_expr::expr::Dialect::Rustc,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let spans: Vec<_> = expr
+        .predicates_with_spans()
+        .map(|sp| (sp.span, sp.key, sp.value))
+        .collect();
+    assert_eq!(spans, [(4..16, 4..8, Some(12..15)), (18..25, 18..25, None)]);
+}
+
+#[test]
+fn literals() {
+    test_validate!(ok [
+        "true" => [],
+        "false" => [],
+        "all(true, unix, not(false))" => [P::Target(TP::Family(Family::unix))],
+    ]);
+
+    err!(r#"true = "yes""# => &[]; 8..11);
+    err!(r#"any(false = "", unix)"# => &[]; 13..13);
+}
+
+#[test]
+fn version() {
+    use cfg_expr::expr::RustcVersion;
+
+    let v = |major, minor, patch| RustcVersion {
+        major,
+        minor,
+        patch,
+    };
+
+    test_validate!(ok [
+        r#"version("1.80")"# => [P::Version { version: v(1, 80, 0), span: 9..13 }],
+        r#"version("1.80.2")"# => [P::Version { version: v(1, 80, 2), span: 9..15 }],
+        r#"any(version ( "1.0" ), not(version("2.3.4")))"# => [
+            P::Version { version: v(1, 0, 0), span: 15..18 },
+            P::Version { version: v(2, 3, 4), span: 36..41 },
+        ],
+        "version" => [P::Flag("version")],
+        r#"version = "1.80""# => [P::KeyValue { key: "version", val: "1.80" }],
+    ]);
+
+    err!(r#"version("1")"# => InvalidVersion @ 9..10);
+    err!(r#"version("1.80-nightly")"# => InvalidVersion @ 9..21);
+    err!(r#"version("1.2.3.4")"# => InvalidVersion @ 9..16);
+    err!(r#"version("+1.2")"# => InvalidVersion @ 9..13);
+    err!(r#"version("")"# => InvalidVersion @ 9..9);
+    err!(r#"version()"# => &["\"<version>\""]; 8..9);
+    err!(r#"version(unix)"# => &["\"<version>\""]; 8..12);
+    err!(r#"version("1.80", "1.81")"# => &[")"]; 14..15);
+    err!(r#"version("1.80""# => UnclosedParens @ 7..14);
+    err!(r#"all(version("1.80"), unix"# => UnclosedParens @ 3..25);
+    err!(r#"version("1.80"), unix"# => MultipleRootPredicates @ 0..21);
+}
+
+#[test]
+fn raw_identifiers() {
+    test_validate!(ok [
+        "r#true" => [P::Flag("true")],
+        r#"any(r#false = "x", r#foo)"# => [
+            P::KeyValue { key: "false", val: "x" },
+            P::Flag("foo"),
+        ],
+        r#"all(r#unix, r#feature = "a", r#target_os = "linux")"# => [
+            P::Target(TP::Family(Family::unix)),
+            P::Feature("a"),
+            P::Target(TP::Os(Os::linux)),
+        ],
+        "r#version" => [P::Flag("version")],
+    ]);
+
+    for (pred, raw) in Expression::parse("any(r#true, r#foo = \"\", foo, r#unix, r#all)")
+        .unwrap()
+        .predicates()
+        .zip([true, false, false, false, true])
+    {
+        assert_eq!(pred.is_raw(), raw, "{pred}");
+        assert_eq!(pred.to_string().starts_with("r#"), raw, "{pred}");
+    }
+
+    // The prefix isn't part of the name, so raw and non-raw identifiers are
+    // the same predicate
+    let raw = Expression::parse(r#"all(r#foo, r#bar = "x")"#).unwrap();
+    let plain = Expression::parse(r#"all(foo, bar = "x")"#).unwrap();
+    assert!(raw.predicates().eq(plain.predicates()));
+    assert!(raw.is_equivalent(&plain));
+    assert!(
+        Expression::parse("true")
+            .unwrap()
+            .implies(&Expression::parse("any(foo, not(r#foo))").unwrap())
+    );
+    assert_eq!(
+        Expression::parse("all(foo, r#foo)")
+            .unwrap()
+            .simplify()
+            .original(),
+        "foo"
+    );
+
+    // Keys that can only be written raw are rendered with the prefix, so they
+    // can be parsed again
+    let expr = Expression::try_from(P::Flag("all")).unwrap();
+    assert_eq!(expr.original(), "r#all");
+    let sp = expr.predicates_with_spans().next().unwrap();
+    assert_eq!((sp.predicate, sp.key), (P::Flag("all"), 0..5));
+    assert_eq!(Expression::parse("r#all").unwrap(), expr);
+
+    // Including Rust's keywords, with the key span covering the prefix
+    let expr = Expression::try_from(P::KeyValue {
+        key: "fn",
+        val: "x",
+    })
+    .unwrap();
+    assert_eq!(expr.original(), r#"r#fn = "x""#);
+    let sp = expr.predicates_with_spans().next().unwrap();
+    assert_eq!((sp.key, sp.value), (0..4, Some(8..9)));
+    assert_eq!(Expression::parse(r#"r#fn = "x""#).unwrap(), expr);
+    assert!(!P::Flag("self").is_raw());
+
+    err!("r#" => &["<key>", "all", "any", "not"]; 1..2);
+    err!(r#"r#version("1.80")"# => &["=", ",", ")"]; 9..10);
+}
+
+#[test]
+fn builtin_cfgs() {
+    use cfg_expr::expr::{FmtDebug, RelocationModel, Sanitizer};
+
+    test_validate!(ok [
+        r#"all(overflow_checks, ub_checks, doctest, miri, contract_checks)"# => [
+            P::OverflowChecks,
+            P::UbChecks,
+            P::Doctest,
+            P::Miri,
+            P::ContractChecks,
+        ],
+        r#"any(sanitize = "kernel-address", fmt_debug = "none", relocation_model = "ropi-rwpi")"# => [
+            P::Sanitize(Sanitizer::KernelAddress),
+            P::FmtDebug(FmtDebug::None),
+            P::RelocationModel(RelocationModel::RopiRwpi),
+        ],
+        r#"all(target_has_atomic_load_store = "8", target_has_atomic_equal_alignment = "ptr")"# => [
+            P::Target(TP::HasAtomicLoadStore(HasAtomic::IntegerSize(8))),
+            P::Target(TP::HasAtomicEqualAlignment(HasAtomic::Pointer)),
+        ],
+        "all(target_thread_local, target_has_reliable_f16, target_has_reliable_f128_math)" => [
+            P::Target(TP::ThreadLocal),
+            P::Target(TP::HasReliableF16),
+            P::Target(TP::HasReliableF128Math),
+        ],
+        "r#miri" => [P::Miri],
+    ]);
+
+    err!(r#"sanitize = "addr""# => &[
+        "address",
+        "cfi",
+        "dataflow",
+        "hwaddress",
+        "kcfi",
+        "kernel-address",
+        "kernel-hwaddress",
+        "leak",
+        "memory",
+        "memtag",
+        "realtime",
+        "safestack",
+        "shadow-call-stack",
+        "thread",
+    ]; 12..16);
+    err!(r#"fmt_debug = """# => &["full", "shallow", "none"]; 13..13);
+    err!("relocation_model" => &["= \"<relocation_model>\""]; 0..16);
+    err!(r#"miri = "yes""# => &[]; 8..11);
+    err!(r#"target_thread_local = "yes""# => &[]; 23..26);
+    err!(r#"target_has_atomic_load_store = "huge""# => InvalidHasAtomic @ 32..36);
+    err!("target_has_atomic_equal_alignment" => &["= \"<target_cfg_value>\""]; 0..33);
+}
+
+#[test]
+fn diagnostics() {
+    let diag = |text: &str| Expression::parse(text).unwrap_err().diagnostic();
+
+    // Typos in keys and values are suggested from the expected terms
+    let d = diag("all(unix, target_arc = \"x86_64\")");
+    assert_eq!(d.message, "unexpected `target_arc`");
+    assert_eq!(d.suggestion.as_deref(), Some("target_arch"));
+
+    err!(r#"fmt_debug = "ful""# => &["full", "shallow", "none"]; 13..16);
+    let d = diag("any(\n  unix,\n  fmt_debug = \"ful\"\n)");
+    assert_eq!(d.suggestion.as_deref(), Some("full"));
+    assert_eq!(d.line_column(d.primary.span.start), (3, 16));
+    assert_eq!(
+        d.to_string(),
+        r#"error: unexpected `ful`
+ --> 3:16
+  |
+3 |   fmt_debug = "ful"
+  |   --------- for this key
+  |                ^^^ did you mean `full`?
+"#
+    );
+
+    // Terms that aren't close to any of the expected ones aren't suggested
+    let d = diag(r#"sanitize = "everything""#);
+    assert_eq!(d.suggestion, None);
+    assert!(d.message.starts_with("expected one of `address`"));
+
+    // Unbalanced parens only point at the parens themselves
+    let d = diag("all(\n  unix,\n  windows");
+    assert_eq!(d.primary.span, 3..4);
+    assert_eq!(
+        d.to_string(),
+        "error: unclosed parens\n --> 1:4\n  |\n1 | all(\n  |    ^\n"
+    );
+
+    // Values of the builtin cfgs are suggested from everything rustc knows
+    for (text, suggestion) in [
+        (r#"sanitize = "adress""#, "address"),
+        (r#"fmt_debug = "ful""#, "full"),
+        (r#"relocation_model = "ropi-rwp""#, "ropi-rwpi"),
+    ] {
+        assert_eq!(diag(text).suggestion.as_deref(), Some(suggestion), "{text}");
+    }
+
+    let strict = |text: &str| {
+        let err = Expression::parse_with_options(text, cfg_expr::expr::ParseOptions::strict())
+            .unwrap_err();
+        assert_eq!(err.reason, Reason::UnknownBuiltin);
+        err.diagnostic()
+    };
+    for (text, suggestion) in [
+        (r#"target_os = "linuxx""#, Some("linux")),
+        (r#"any(unix, target_arch = "x86-64")"#, Some("x86_64")),
+        (r#"target_vendor = "aple""#, Some("apple")),
+        (r#"target_family = "wasmm""#, Some("wasm")),
+        (r#"panic = "unwnd""#, Some("unwind")),
+        (r#"r#target_env = "gnux""#, Some("gnu")),
+        (r#"target_os = "templeos""#, None),
+    ] {
+        assert_eq!(strict(text).suggestion.as_deref(), suggestion, "{text}");
+    }
+
+    // The key is found after multibyte whitespace
+    for ws in ['\u{A0}', '\u{85}'] {
+        let text = format!("all(unix,{ws}target_os = \"linuxx\")");
+        let d = strict(&text);
+        assert_eq!(d.suggestion.as_deref(), Some("linux"), "{text:?}");
+        assert_eq!(&text[d.secondary[0].span.clone()], "target_os", "{text:?}");
+    }
+    assert_eq!(
+        strict(r#"target_os = "linuxx""#).to_string(),
+        r#"error: unexpected `linuxx`
+ --> 1:14
+  |
+1 | target_os = "linuxx"
+  | --------- for this key
+  |              ^^^^^^ did you mean `linux`?
+"#
+    );
+
+    // Non-ASCII characters are pointed at as a whole, and columns are counted
+    // in characters rather than bytes
+    err!("all(unix, é)" => &["<key>", "all", "any", "not"]; 10..12);
+    let d = diag("all(unix, é)");
+    assert_eq!(d.line_column(11), (1, 11));
+    assert_eq!(
+        d.to_string(),
+        "error: expected one of `<key>`, `all`, `any`, `not` here\n --> 1:11\n  |\n1 | all(unix, é)\n  |           ^\n"
+    );
+}
+
+#[test]
+fn recovers_all_errors() {
+    let recover = |s: &str| {
+        let recovered = Expression::parse_recovering(s);
+        let errors: Vec<_> = recovered
+            .errors
+            .into_iter()
+            .map(|e| (e.original[e.span].to_owned(), e.reason))
+            .collect();
+        (recovered.expr.map(|e| e.original().to_owned()), errors)
+    };
+
+    // Valid expressions are the same as a strict parse
+    for valid in [
+        "unix",
+        r#"cfg(all(unix, not(target_os = "macos"), feature = "std"))"#,
+        r#"any(version("1.80"), r#true)"#,
+    ] {
+        let recovered = Expression::parse_recovering(valid);
+        assert!(recovered.errors.is_empty());
+        assert_eq!(recovered.expr, Some(Expression::parse(valid).unwrap()));
+    }
+
+    // Missing commas are assumed, bad predicates are dropped
+    let (expr, errors) = recover(r#"all(unix windows, fmt_debug = "ful", feature = "a")"#);
+    assert_eq!(
+        expr.as_deref(),
+        Some(r#"all(unix, windows, feature = "a")"#)
+    );
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[0],
+        ("windows".to_owned(), Reason::Unexpected(&[",", ")"]))
+    );
+    assert_eq!(
+        errors[1],
+        (
+            "ful".to_owned(),
+            Reason::Unexpected(&["full", "shallow", "none"])
+        )
+    );
+
+    // Unbalanced parens
+    let (expr, errors) = recover("any(unix, all(windows)), not(unix");
+    assert_eq!(expr.as_deref(), Some("any(unix, all(windows))"));
+    assert_eq!(
+        errors,
+        [
+            (
+                "any(unix, all(windows)), not(unix".to_owned(),
+                Reason::MultipleRootPredicates
+            ),
+            ("(unix".to_owned(), Reason::UnclosedParens),
+        ]
+    );
+
+    let (expr, errors) = recover("all(unix)), not(unix, windows)");
+    assert_eq!(expr.as_deref(), Some("all(unix)"));
+    assert!(errors.contains(&(")".to_owned(), Reason::UnopenedParens)));
+    assert!(errors.contains(&("not(unix, windows)".to_owned(), Reason::InvalidNot(2))));
+
+    // Lexing continues after bad characters and unclosed quotes
+    let (expr, errors) = recover(r#"any(unix, wind@ws, feature = "std)"#);
+    assert_eq!(expr.as_deref(), Some("any(unix, wind, ws)"));
+    assert_eq!(
+        &errors[1..],
+        [
+            (
+                "@".to_owned(),
+                Reason::Unexpected(&["<key>", "all", "any", "not"])
+            ),
+            ("ws".to_owned(), Reason::Unexpected(&[",", ")"])),
+            ("\"std)".to_owned(), Reason::UnclosedQuotes),
+        ]
+    );
+
+    // Both parsers agree on which commas are errors
+    for text in [
+        "all(a,,b, feature)",
+        "all(a,,b)",
+        "any(a,,,)",
+        "unix,,",
+        "all(,a)",
+        ",unix",
+        "not(a,,)",
+        "all(a b,,c)",
+    ] {
+        let strict = Expression::parse(text);
+        let recovered = Expression::parse_recovering(text);
+
+        match strict {
+            Ok(expr) => {
+                assert!(recovered.errors.is_empty(), "{text}");
+                assert_eq!(recovered.expr, Some(expr));
+            }
+            Err(err) => {
+                assert_eq!(recovered.errors.len(), 1, "{text}");
+                assert_eq!(recovered.errors[0].span, err.span, "{text}");
+            }
+        }
+    }
+
+    let (_, errors) = recover("all(a,,b, feature)");
+    assert_eq!(
+        errors,
+        [(
+            "feature".to_owned(),
+            Reason::Unexpected(&["= \"<feature_name>\""])
+        )]
+    );
+
+    // Nothing to salvage
+    let (expr, errors) = recover("");
+    assert_eq!(expr, None);
+    assert_eq!(errors, [(String::new(), Reason::Empty)]);
+}
+
+#[test]
+fn strict_values() {
+    let strict = |s: &str| Expression::parse_with_options(s, ParseOptions::strict());
+
+    for valid in [
+        r#"all(target_os = "linux", target_arch = "x86_64", target_env = "gnu", target_vendor = "unknown")"#,
+        r#"any(target_family = "wasm", target_abi = "eabihf", panic = "abort")"#,
+        // The values of targets without an OS, environment, or vendor
+        r#"all(target_os = "none", target_env = "", target_abi = "", target_vendor = "unknown")"#,
+        // Only the values of the target predicates above are checked
+        r#"all(feature = "linuxx", target_feature = "sse9", target_pointer_width = "128", other = "x")"#,
+    ] {
+        assert_eq!(strict(valid).unwrap(), Expression::parse(valid).unwrap());
+    }
+
+    for (expr, span) in [
+        (r#"target_os = "linuxx""#, 13..19),
+        (r#"target_arch = "x86-64""#, 15..21),
+        (r#"target_env = "glibc""#, 14..19),
+        (r#"target_vendor = "aple""#, 17..21),
+        (r#"target_family = "linux""#, 17..22),
+        (r#"target_abi = "x33""#, 14..17),
+        (r#"any(unix, panic = "unwnd")"#, 19..24),
+    ] {
+        // The default is lenient
+        assert!(Expression::parse(expr).is_ok());
+
+        let err = strict(expr).unwrap_err();
+        assert_eq!(err.reason, Reason::UnknownBuiltin, "{expr}");
+        assert_eq!(err.span, span, "{expr}");
+    }
+}
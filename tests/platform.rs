@@ -0,0 +1,106 @@
+use cfg_expr::{
+    Predicate,
+    error::Reason,
+    targets::{Platform, Triple, get_builtin_target_by_triple},
+};
+
+#[test]
+fn parses_both_forms() {
+    let platform: Platform = "aarch64-apple-darwin".parse().unwrap();
+    assert_eq!(
+        platform,
+        Platform::Triple(Triple::new_const("aarch64-apple-darwin"))
+    );
+    assert_eq!(platform.to_string(), "aarch64-apple-darwin");
+
+    let platform: Platform = r#"cfg(any(unix, target_os = "wasi"))"#.parse().unwrap();
+    let Platform::Cfg(expr) = &platform else {
+        panic!("expected a cfg() platform");
+    };
+    assert_eq!(expr.original(), r#"any(unix, target_os = "wasi")"#);
+    assert_eq!(
+        platform.to_string(),
+        r#"cfg(any(unix, target_os = "wasi"))"#
+    );
+    assert_eq!(platform.to_string().parse::<Platform>().unwrap(), platform);
+
+    // Cargo accepts any name, with or without a value
+    let platform: Platform = r#"cfg(any(feature, unix = "yes"))"#.parse().unwrap();
+    let Platform::Cfg(expr) = &platform else {
+        panic!("expected a cfg() platform");
+    };
+    assert_eq!(
+        expr.predicates().collect::<Vec<_>>(),
+        [
+            Predicate::Flag("feature"),
+            Predicate::KeyValue {
+                key: "unix",
+                val: "yes"
+            }
+        ]
+    );
+}
+
+#[test]
+fn matches_targets() {
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let windows = get_builtin_target_by_triple("x86_64-pc-windows-msvc").unwrap();
+
+    let triple: Platform = "x86_64-unknown-linux-gnu".parse().unwrap();
+    assert!(triple.matches(linux, |_| true));
+    assert!(!triple.matches(windows, |_| true));
+
+    let cfg: Platform = r#"cfg(all(unix, feature = "std"))"#.parse().unwrap();
+    let std = |pred: &Predicate<'_>| matches!(pred, Predicate::Feature("std"));
+    assert!(cfg.matches(linux, std));
+    assert!(!cfg.matches(linux, |_| false));
+    assert!(!cfg.matches(windows, std));
+}
+
+#[test]
+fn fails_invalid() {
+    let err = |s: &str| s.parse::<Platform>().unwrap_err();
+
+    // Errors in the expression point at the full spec
+    let e = err("cfg(all(unix)");
+    assert_eq!(e.original, "cfg(all(unix)");
+    assert_eq!(e.span, 7..12);
+    assert_eq!(e.reason, Reason::UnclosedParens);
+
+    let e = err("cfg(target_os = 5)");
+    assert_eq!(&e.original[e.span.clone()], "5");
+
+    // Only the expressions Cargo accepts are valid
+    let e = err("cfg(all(unix,\twindows))");
+    assert_eq!(e.span, 13..14);
+
+    let e = err(r#"cfg(version("1.80"))"#);
+    assert_eq!(e.span, 11..12);
+
+    let e = err("cfg(not(unix,))");
+    assert_eq!(e.span, 12..13);
+    assert_eq!(e.reason, Reason::Unexpected(&[")"]));
+
+    let e = err("");
+    assert_eq!(e.reason, Reason::Empty);
+
+    let e = err("x86_64 unknown linux");
+    assert_eq!(e.span, 6..7);
+    assert_eq!(e.reason, Reason::InvalidCharacters);
+
+    let e = err("cfg(unix");
+    assert_eq!(e.span, 3..4);
+    assert_eq!(e.reason, Reason::InvalidCharacters);
+
+    let e = err("not-a-real-target");
+    assert_eq!(e.span, 0..17);
+    assert_eq!(e.reason, Reason::UnknownBuiltin);
+}
+
+#[cfg(feature = "targets")]
+#[test]
+fn lexicon_triples() {
+    // Not a builtin, but a valid triple nonetheless
+    let platform: Platform = "x86_64-unknown-linux-gnux32".parse().unwrap();
+    assert_eq!(platform.to_string(), "x86_64-unknown-linux-gnux32");
+}
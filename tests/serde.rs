@@ -0,0 +1,174 @@
+#![cfg(feature = "serde")]
+
+use cfg_expr::{
+    Expression, ParseError,
+    error::Reason,
+    expr::{Dialect, OwnedPredicate, ParseOptions, RelocationModel, Sanitizer},
+    targets::{
+        ALL_BUILTINS, CfgSet, Families, Family, HasAtomic, HasAtomics, Platform, TargetInfo,
+    },
+};
+use serde_json::{from_str, json, to_string, to_value};
+
+#[test]
+fn expressions() {
+    let text = r#"all(any(unix, target_os = "wasi"), not(feature = "std"), version("1.80"))"#;
+    let expr = Expression::parse(text).unwrap();
+
+    assert_eq!(to_value(&expr).unwrap(), json!(text));
+
+    let de: Expression = from_str(&to_string(&expr).unwrap()).unwrap();
+    assert_eq!(de, expr);
+    assert_eq!(de.original(), text);
+
+    // Expressions parsed with any of the dialects can be deserialized
+    for (text, dialect) in [
+        (r#"all(feature, unix = "x")"#, Dialect::Rustc),
+        ("all(unix,,)", Dialect::Strict),
+        (r#"any(target_os = "linux",)"#, Dialect::Cargo),
+    ] {
+        let options = ParseOptions {
+            dialect,
+            ..Default::default()
+        };
+        let expr = Expression::parse_with_options(text, options).unwrap();
+
+        let de: Expression = from_str(&to_string(&expr).unwrap()).unwrap();
+        assert_eq!(de, expr);
+    }
+
+    let owned: OwnedPredicate = from_str(r#""feature""#).unwrap();
+    assert_eq!(owned, OwnedPredicate::Flag("feature".to_owned()));
+
+    // The string is validated by the parser
+    let err = from_str::<Expression>(r#""all(unix""#).unwrap_err();
+    assert!(err.to_string().contains("unclosed parens"), "{err}");
+
+    let preds: Vec<_> = expr.predicates().collect();
+    assert_eq!(
+        to_value(&preds).unwrap(),
+        json!([
+            "unix",
+            r#"target_os = "wasi""#,
+            r#"feature = "std""#,
+            r#"version("1.80.0")"#
+        ])
+    );
+
+    let owned: Vec<OwnedPredicate> = from_str(&to_string(&preds).unwrap()).unwrap();
+    assert_eq!(to_value(&owned).unwrap(), to_value(&preds).unwrap(),);
+    assert_eq!(owned[1].as_predicate(), preds[1]);
+
+    // The values of the builtin cfgs use their cfg syntax as well
+    let san: Sanitizer = from_str(r#""shadow-call-stack""#).unwrap();
+    assert_eq!(san, Sanitizer::ShadowCallStack);
+    assert_eq!(to_value(san).unwrap(), json!("shadow-call-stack"));
+    assert!(from_str::<RelocationModel>(r#""dynamic""#).is_err());
+
+    let err = from_str::<OwnedPredicate>(r#""any(unix)""#).unwrap_err();
+    assert!(
+        err.to_string().contains("expected a single predicate"),
+        "{err}"
+    );
+}
+
+#[test]
+fn targets() {
+    for builtin in ALL_BUILTINS {
+        let de: TargetInfo = from_str(&to_string(builtin).unwrap()).unwrap();
+        assert_eq!(&de, builtin);
+    }
+
+    let linux =
+        cfg_expr::targets::get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    assert_eq!(
+        to_value(linux).unwrap(),
+        json!({
+            "triple": "x86_64-unknown-linux-gnu",
+            "os": "linux",
+            "abi": null,
+            "arch": "x86_64",
+            "env": "gnu",
+            "vendor": "unknown",
+            "families": ["unix"],
+            "pointer_width": 64,
+            "endian": "little",
+            "has_atomics": ["8", "16", "32", "64", "ptr"],
+            "has_atomics_load_store": ["8", "16", "32", "64", "ptr"],
+            "has_atomics_equal_alignment": ["8", "16", "32", "64", "ptr"],
+            "panic": "unwind",
+            "target_features": ["fxsr", "sse", "sse2"],
+            "thread_local": true,
+            "reliable_floats": { "f16": true, "f16_math": true, "f128": true, "f128_math": false },
+            "metadata": { "tier": 1, "std": true, "host_tools": true },
+        })
+    );
+
+    // Sets are sorted when deserialized, regardless of the input order
+    let families: Families = from_str(r#"["windows", "unix"]"#).unwrap();
+    assert_eq!(families, Families::new([Family::unix, Family::windows]));
+    let has_atomics: HasAtomics = from_str(r#"["ptr", "8"]"#).unwrap();
+    assert_eq!(
+        has_atomics,
+        HasAtomics::new([HasAtomic::IntegerSize(8), HasAtomic::Pointer])
+    );
+    assert!(from_str::<HasAtomics>(r#"["huge"]"#).is_err());
+
+    let cfgs = CfgSet::from_print_cfg(
+        "custom",
+        "target_arch=\"arm\"\ntarget_endian=\"big\"\ntarget_pointer_width=\"32\"\nmy_flag\n",
+    )
+    .unwrap();
+    let de: CfgSet = from_str(&to_string(&cfgs).unwrap()).unwrap();
+    assert_eq!(de, cfgs);
+
+    let platforms: Vec<Platform> = from_str(r#"["cfg(unix)", "x86_64-pc-windows-msvc"]"#).unwrap();
+    assert_eq!(
+        to_value(&platforms).unwrap(),
+        json!(["cfg(unix)", "x86_64-pc-windows-msvc"])
+    );
+    assert!(from_str::<Platform>(r#""cfg(unix""#).is_err());
+}
+
+#[test]
+fn errors() {
+    for text in [
+        "all(unix",
+        "target_os = 5",
+        r#"target_os = "linux"#,
+        "not(a, b)",
+        "unix)",
+        r#"target_os = "linux" = "#,
+        r#"sanitize = "nope""#,
+        "sanitize",
+        "target_nope",
+    ] {
+        let err = Expression::parse(text).unwrap_err();
+        let de: ParseError = from_str(&to_string(&err).unwrap()).unwrap();
+        assert_eq!(de, err);
+    }
+
+    let err = Expression::parse("all(unix) = ").unwrap_err();
+    assert_eq!(
+        to_value(&err).unwrap(),
+        json!({
+            "original": "all(unix) = ",
+            "span": { "start": 10, "end": 11 },
+            "reason": { "Unexpected": [")", ","] },
+        })
+    );
+
+    // Only the lists of expected terms that the crate creates can be
+    // deserialized, as they are `'static`
+    let de: ParseError = from_str(
+        r#"{ "original": "x", "span": { "start": 0, "end": 1 }, "reason": { "Unexpected": ["big", "little"] } }"#,
+    )
+    .unwrap();
+    assert_eq!(de.reason, Reason::Unexpected(&["big", "little"]));
+    assert!(
+        from_str::<ParseError>(
+            r#"{ "original": "x", "span": { "start": 0, "end": 1 }, "reason": { "Unexpected": ["y"] } }"#,
+        )
+        .is_err()
+    );
+}
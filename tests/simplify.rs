@@ -0,0 +1,109 @@
+use cfg_expr::{Expression, expr::OwnedPredicate};
+
+/// Checks that both expressions evaluate the same for every combination of
+/// their predicates
+fn assert_equivalent(a: &Expression, b: &Expression) {
+    let mut preds = Vec::<OwnedPredicate>::new();
+    for pred in a.predicates().chain(b.predicates()) {
+        let pred = OwnedPredicate::from(pred);
+        if !preds.contains(&pred) {
+            preds.push(pred);
+        }
+    }
+
+    assert!(preds.len() < 16, "too many predicates to check");
+
+    for mask in 0u32..1 << preds.len() {
+        let eval = |expr: &Expression| {
+            expr.eval(|pred| {
+                let pred = OwnedPredicate::from(pred.clone());
+                let ind = preds.iter().position(|p| *p == pred).unwrap();
+                mask & (1 << ind) != 0
+            })
+        };
+
+        assert_eq!(
+            eval(a),
+            eval(b),
+            "'{a}' and '{b}' differ for assignment {mask:b}"
+        );
+    }
+}
+
+macro_rules! simplifies {
+    ($($text:expr => $expected:expr),+$(,)?) => {
+        $(
+            let expr = Expression::parse($text).unwrap();
+            let simplified = expr.simplify();
+
+            similar_asserts::assert_eq!(simplified.original(), $expected, "simplifying '{}'", $text);
+            assert_equivalent(&expr, &simplified);
+            // Simplification should be idempotent
+            similar_asserts::assert_eq!(simplified.simplify().original(), $expected);
+        )+
+    };
+}
+
+#[test]
+fn flattens() {
+    simplifies!(
+        "all(a, all(b, all(c)), d)" => "all(a, b, c, d)",
+        "any(a, any(b, any(c)), d)" => "any(a, b, c, d)",
+        "all(a, any(b, c))" => "all(a, any(b, c))",
+        "all(a)" => "a",
+        "any(all(any(a)))" => "a",
+    );
+}
+
+#[test]
+fn removes_double_negation() {
+    simplifies!(
+        "not(not(a))" => "a",
+        "not(not(not(a)))" => "not(a)",
+        "all(not(not(a)), not(not(b)))" => "all(a, b)",
+    );
+}
+
+#[test]
+fn deduplicates() {
+    simplifies!(
+        "all(a, b, a)" => "all(a, b)",
+        "any(unix, target_family = \"unix\")" => "unix",
+        "all(feature = \"a\", any(feature = \"a\"), not(not(feature = \"a\")))" => "feature = \"a\"",
+    );
+}
+
+#[test]
+fn folds_constants() {
+    simplifies!(
+        "all()" => "all()",
+        "any()" => "any()",
+        "not(all())" => "any()",
+        "not(any())" => "all()",
+        "all(a, all())" => "a",
+        "all(a, any())" => "any()",
+        "any(a, any())" => "a",
+        "any(a, all())" => "all()",
+        "any(a, not(any()))" => "all()",
+        "all(a, not(a))" => "any()",
+        "any(not(a), a)" => "all()",
+        "true" => "all()",
+        "not(false)" => "all()",
+        "all(a, true)" => "a",
+        "any(a, false, b)" => "any(a, b)",
+        "all(a, false)" => "any()",
+        "any(true, a)" => "all()",
+    );
+}
+
+#[test]
+fn absorbs() {
+    simplifies!(
+        "all(unix, any(unix, windows))" => "unix",
+        "any(unix, all(unix, windows))" => "unix",
+        "all(any(a, b), any(b, a, c))" => "any(a, b)",
+        "any(all(a, b), all(b, a))" => "all(a, b)",
+        "any(all(a, b, c), all(a, b), d)" => "any(all(a, b), d)",
+        "all(any(a, b), any(a, c))" => "all(any(a, b), any(a, c))",
+    );
+}
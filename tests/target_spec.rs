@@ -0,0 +1,236 @@
+use cfg_expr::{
+    error::TargetSpecReason as Reason,
+    targets::{
+        Arch, Endian, Family, HasAtomic, Os, Panic, ReliableFloats, TargetInfo, TargetMetadata,
+        find_target_spec_in, get_builtin_target_by_triple,
+    },
+};
+
+// RUSTC_BOOTSTRAP=1 rustc -Z unstable-options --print target-spec-json --target thumbv7em-none-eabihf
+const THUMB: &str = r#"{
+  "abi": "eabihf",
+  "arch": "arm",
+  "c-enum-min-bits": 8,
+  "crt-objects-fallback": "false",
+  "data-layout": "e-m:e-p:32:32-Fi8-i64:64-v128:64:128-a:0:32-n32-S64",
+  "emit-debug-gdb-scripts": false,
+  "features": "+vfp4d16sp",
+  "frame-pointer": "always",
+  "linker": "rust-lld",
+  "linker-flavor": "gnu-lld",
+  "llvm-floatabi": "hard",
+  "llvm-target": "thumbv7em-none-eabihf",
+  "max-atomic-width": 32,
+  "metadata": {
+    "description": "Bare ARMv7E-M, hardfloat",
+    "host_tools": false,
+    "std": false,
+    "tier": 2
+  },
+  "panic-strategy": "abort",
+  "relocation-model": "static",
+  "target-pointer-width": 32
+}"#;
+
+// An older style spec, where integers were strings and the family was a single string
+const BOARD: &str = r#"{
+    "llvm-target": "mips-unknown-elf",
+    "arch": "mips",
+    "os": "our-os",
+    "vendor": "acme",
+    "env": "",
+    "target-family": "unix",
+    "target-endian": "big",
+    "target-pointer-width": "32",
+    "target-c-int-width": "32",
+    "max-atomic-width": "64",
+    "min-atomic-width": 16,
+    "pre-link-args": { "gcc": ["-Wl,--as-needed", "-T\"link.x\""] },
+    "position-independent-executables": null
+}"#;
+
+#[test]
+fn matches_builtin() {
+    let info = TargetInfo::from_target_spec_json(THUMB).unwrap();
+    let mut builtin = get_builtin_target_by_triple("thumbv7em-none-eabihf")
+        .unwrap()
+        .clone();
+
+    // The LLVM features in the spec aren't mapped to rustc's target features
+    assert!(info.target_features.is_empty());
+    assert_eq!(
+        info.metadata,
+        TargetMetadata {
+            tier: Some(2),
+            std: Some(false),
+            host_tools: Some(false),
+        }
+    );
+    builtin.target_features = info.target_features.clone();
+    // Nor does it specify which floats the codegen backend reliably supports
+    assert_eq!(info.reliable_floats, ReliableFloats::NONE);
+    builtin.reliable_floats = info.reliable_floats;
+
+    assert_eq!(info, builtin);
+}
+
+#[test]
+fn custom_target() {
+    let info = TargetInfo::from_target_spec_json(BOARD).unwrap();
+
+    assert_eq!(info.triple.as_str(), "mips-unknown-elf");
+    assert_eq!(info.arch, Arch::mips);
+    assert_eq!(info.os, Some(Os::new("our-os")));
+    assert_eq!(info.vendor.as_ref().map(|v| v.as_str()), Some("acme"));
+    assert_eq!(info.env, None);
+    assert_eq!(info.abi, None);
+    assert_eq!(info.families.as_ref(), [Family::unix]);
+    assert_eq!(info.endian, Endian::big);
+    assert_eq!(info.pointer_width, 32);
+    assert_eq!(info.panic, Panic::unwind);
+    assert_eq!(info.metadata, TargetMetadata::UNKNOWN);
+    assert_eq!(
+        info.has_atomics.as_ref(),
+        [
+            HasAtomic::IntegerSize(16),
+            HasAtomic::IntegerSize(32),
+            HasAtomic::IntegerSize(64),
+            HasAtomic::Pointer,
+        ]
+    );
+    assert_eq!(info.has_atomics_load_store, info.has_atomics);
+    // Without a data layout, 64 bit integers and pointers are aligned to 32
+    // and 64 bits respectively, the same as rustc
+    assert_eq!(
+        info.has_atomics_equal_alignment.as_ref(),
+        [HasAtomic::IntegerSize(16), HasAtomic::IntegerSize(32)]
+    );
+    assert!(!info.thread_local);
+
+    // Without compare and swap there are no `target_has_atomic` cfgs at all
+    let no_cas = TargetInfo::from_target_spec_json(
+        r#"{"arch": "riscv32", "target-pointer-width": 32, "atomic-cas": false, "vendor": ""}"#,
+    )
+    .unwrap();
+    assert!(no_cas.has_atomics.is_empty());
+    // But loads and stores are still supported
+    assert_eq!(no_cas.has_atomics_load_store.len(), 4);
+    assert_eq!(no_cas.os, None);
+    assert_eq!(no_cas.vendor.as_ref().map(|v| v.as_str()), Some("unknown"));
+    assert!(no_cas.families.is_empty());
+}
+
+#[test]
+fn fails_invalid() {
+    let err = |json: &str| TargetInfo::from_target_spec_json(json).unwrap_err();
+
+    // Deeply nested values are rejected rather than overflowing the stack
+    let nested = format!(
+        r#"{{"arch": "x86_64", "target-pointer-width": 64, "x": {}{}}}"#,
+        "[".repeat(100_000),
+        "]".repeat(100_000)
+    );
+    let e = err(&nested);
+    assert_eq!(e.span, 179..180);
+    assert_eq!(e.reason, Reason::DepthLimitExceeded);
+    assert_eq!(e.to_string(), "nested too deeply at 1:180");
+
+    // The root object is the first level of nesting
+    let nested = format!(
+        r#"{{"arch": "x86_64", "target-pointer-width": 64, "x": {}{}}}"#,
+        "[".repeat(127),
+        "]".repeat(127)
+    );
+    assert!(TargetInfo::from_target_spec_json(&nested).is_ok());
+
+    let e = err(r#"{"target-pointer-width": 64}"#);
+    assert_eq!(e.span, 27..28);
+    assert_eq!(e.reason, Reason::MissingField("arch"));
+
+    let e = err(r#"{"arch": "x86_64"}"#);
+    assert_eq!(e.reason, Reason::MissingField("target-pointer-width"));
+
+    let e = err(r#"{"arch": "x86_64", "target-pointer-width": "sixty-four"}"#);
+    assert_eq!(e.span, 44..54);
+    assert_eq!(e.reason, Reason::InvalidInteger);
+
+    let e = err(r#"{"arch": "x86_64", "target-pointer-width": 256}"#);
+    assert_eq!(e.span, 43..46);
+    assert_eq!(e.reason, Reason::InvalidInteger);
+
+    let e = err(r#"{"arch": "x86_64", "target-pointer-width": 64, "target-endian": "middle"}"#);
+    assert_eq!(e.span, 65..71);
+    assert_eq!(e.reason, Reason::InvalidValue(&["big", "little"]));
+
+    let e = err(r#"{"arch": 86, "target-pointer-width": 64}"#);
+    assert_eq!(e.span, 9..11);
+    assert_eq!(e.reason, Reason::InvalidType("string"));
+
+    let e = err(r#"{"arch": "x86_64, "target-pointer-width": 64}"#);
+    assert_eq!(e.span, 19..20);
+    assert_eq!(e.reason, Reason::Unexpected(&[",", "}"]));
+
+    let e = err(r#"{"arch": "x86_64"#);
+    assert_eq!(e.span, 9..16);
+    assert_eq!(e.reason, Reason::UnclosedString);
+
+    let e = err(r#"{"arch": "x86_64""#);
+    assert_eq!(e.span, 0..17);
+    assert_eq!(e.reason, Reason::UnclosedObject);
+
+    let e = err(r#"{"target-family": ["unix""#);
+    assert_eq!(e.span, 18..25);
+    assert_eq!(e.reason, Reason::UnclosedArray);
+
+    let e = err(r#"{"arch": "x86_64"} x"#);
+    assert_eq!(e.span, 19..20);
+    assert_eq!(e.reason, Reason::TrailingCharacters);
+
+    let e = err(r#"["x86_64"]"#);
+    assert_eq!(e.span, 0..10);
+    assert_eq!(e.reason, Reason::InvalidType("object"));
+
+    let e = err(r#"{"arch": "x86_64", "target-pointer-width": 64, "metadata": {"std": "yes"}}"#);
+    assert_eq!(e.span, 67..72);
+    assert_eq!(e.reason, Reason::InvalidType("boolean"));
+
+    let e = err(r#"{"arch": "x86\q"}"#);
+    assert_eq!(e.span, 13..15);
+    assert_eq!(e.reason, Reason::InvalidEscape);
+    assert_eq!(e.to_string(), "invalid escape sequence at 1:14");
+
+    let e = err(r#"{"arch": "\ud800x"}"#);
+    assert_eq!(e.span, 10..16);
+    assert_eq!(e.reason, Reason::InvalidEscape);
+}
+
+#[test]
+fn finds_specs() {
+    let root = std::env::temp_dir().join(format!("cfg-expr-target-spec-{}", std::process::id()));
+    let (first, second) = (root.join("first"), root.join("second"));
+    std::fs::create_dir_all(&first).unwrap();
+    std::fs::create_dir_all(&second).unwrap();
+
+    std::fs::write(second.join("board.json"), BOARD).unwrap();
+    std::fs::write(first.join("thumb.json"), THUMB).unwrap();
+    std::fs::write(second.join("thumb.json"), THUMB).unwrap();
+
+    let dirs = [&first, &second];
+    assert_eq!(
+        find_target_spec_in("board", dirs),
+        Some(second.join("board.json"))
+    );
+    assert_eq!(
+        find_target_spec_in("thumb", dirs),
+        Some(first.join("thumb.json"))
+    );
+    assert_eq!(find_target_spec_in("nope", dirs), None);
+
+    let spec = std::fs::read_to_string(find_target_spec_in("board", dirs).unwrap()).unwrap();
+    assert_eq!(
+        TargetInfo::from_target_spec_json(&spec).unwrap().arch,
+        Arch::mips
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
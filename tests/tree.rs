@@ -0,0 +1,141 @@
+use cfg_expr::{
+    Expression,
+    expr::{CfgTree, OwnedPredicate as OP, TargetPredicate as TP},
+    targets::*,
+};
+
+#[test]
+fn expression_to_tree() {
+    let expr = Expression::parse(
+        r#"cfg(all(
+            unix,
+            any(target_os = "linux", target_os = "android", all()),
+            not(any()),
+            not(feature = "nope"),
+            target_pointer_width = "64",
+            key = "value",
+        ))"#,
+    )
+    .unwrap();
+
+    similar_asserts::assert_eq!(
+        expr.to_tree(),
+        CfgTree::All(vec![
+            CfgTree::Pred(OP::Target(TP::Family(Family::unix))),
+            CfgTree::Any(vec![
+                CfgTree::Pred(OP::Target(TP::Os(Os::linux))),
+                CfgTree::Pred(OP::Target(TP::Os(Os::android))),
+                CfgTree::All(vec![]),
+            ]),
+            CfgTree::Not(Box::new(CfgTree::Any(vec![]))),
+            CfgTree::Not(Box::new(CfgTree::Pred(OP::Feature("nope".to_owned())))),
+            CfgTree::Pred(OP::Target(TP::PointerWidth(64))),
+            CfgTree::Pred(OP::KeyValue {
+                key: "key".to_owned(),
+                val: "value".to_owned(),
+            }),
+        ])
+    );
+}
+
+#[test]
+fn roundtrips() {
+    for text in [
+        "unix",
+        "all()",
+        "not(any())",
+        "any(true, not(false))",
+        r#"all(version("1.80.0"), unix)"#,
+        r#"any(r#true, r#false = "x")"#,
+        r#"any(target_feature = "sse2", not(all(test, debug_assertions, proc_macro)))"#,
+        r#"all(target_has_atomic = "ptr", target_endian = "big", panic = "abort", flag, k = "")"#,
+    ] {
+        let expr = Expression::parse(text).unwrap();
+        let tree = expr.to_tree();
+
+        let built = Expression::try_from(&tree).unwrap();
+        assert_eq!(built.original(), text);
+        assert_eq!(tree.to_string(), text);
+        assert_eq!(built.to_tree(), tree);
+    }
+}
+
+#[test]
+fn rewrites() {
+    // Replace every target_os with its negation, which is the kind of pass the
+    // tree is intended for
+    fn negate_os(tree: CfgTree) -> CfgTree {
+        match tree {
+            CfgTree::All(children) => CfgTree::All(children.into_iter().map(negate_os).collect()),
+            CfgTree::Any(children) => CfgTree::Any(children.into_iter().map(negate_os).collect()),
+            CfgTree::Not(child) => CfgTree::Not(Box::new(negate_os(*child))),
+            pred @ CfgTree::Pred(OP::Target(TP::Os(_))) => CfgTree::Not(Box::new(pred)),
+            other => other,
+        }
+    }
+
+    let expr =
+        Expression::parse(r#"any(target_os = "linux", all(unix, target_os = "macos"))"#).unwrap();
+
+    let rewritten = Expression::try_from(negate_os(expr.to_tree())).unwrap();
+    assert_eq!(
+        rewritten.original(),
+        r#"any(not(target_os = "linux"), all(unix, not(target_os = "macos")))"#
+    );
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    assert!(expr.eval(|pred| match pred {
+        cfg_expr::Predicate::Target(tp) => tp.matches(linux),
+        _ => false,
+    }));
+    assert!(rewritten.eval(|pred| match pred {
+        cfg_expr::Predicate::Target(tp) => tp.matches(linux),
+        _ => false,
+    }));
+}
+
+#[test]
+fn owned_predicates() {
+    use std::collections::{BTreeSet, HashMap};
+
+    let owned = {
+        let expr = Expression::parse(
+            r#"all(unix, any(feature = "std", r#flag, key = "value"), version("1.80"), unix)"#,
+        )
+        .unwrap();
+        expr.owned_predicates().collect::<Vec<_>>()
+    };
+
+    // The predicates outlive the expression, and can be sent to other threads
+    let owned = std::thread::spawn(move || owned).join().unwrap();
+
+    assert_eq!(
+        owned,
+        [
+            OP::Target(TP::Family(Family::unix)),
+            OP::Feature("std".to_owned()),
+            OP::Flag("flag".to_owned()),
+            OP::KeyValue {
+                key: "key".to_owned(),
+                val: "value".to_owned()
+            },
+            OP::Version("1.80.0".parse().unwrap()),
+            OP::Target(TP::Family(Family::unix)),
+        ]
+    );
+
+    let unique = owned.iter().cloned().collect::<BTreeSet<_>>();
+    assert_eq!(unique.len(), 5);
+    assert_eq!(unique.first(), Some(&OP::Target(TP::Family(Family::unix))));
+
+    let mut counts = HashMap::<OP, usize>::new();
+    for pred in &owned {
+        *counts.entry(pred.clone()).or_default() += 1;
+    }
+    assert_eq!(counts[&OP::Target(TP::Family(Family::unix))], 2);
+    assert_eq!(counts[&OP::Feature("std".to_owned())], 1);
+
+    let expr = Expression::parse(r#"target_feature = "sse2""#).unwrap();
+    let pred = expr.predicates().next().unwrap();
+    assert_eq!(pred.clone().into_owned().as_predicate(), pred);
+}
@@ -0,0 +1,296 @@
+//! Generates `src/targets/history.rs`, which records how each builtin target
+//! changed over a range of rustc releases
+//!
+//! Every release before the current one is run via a rustup toolchain, eg.
+//! `rustc +1.60`, so they must be installed first, eg.
+//! `rustup toolchain install 1.60 --profile minimal`
+
+use std::{collections::BTreeMap, fmt::Write, process::Command};
+
+pub const HISTORY: &str = "src/targets/history.rs";
+
+/// `target_has_atomic` and the `panic` cfg were both stabilized in 1.60, so
+/// earlier releases don't have enough information to be useful
+pub const FIRST_MINOR: u16 = 60;
+
+/// Assembles the history, `rustc` is used for the current `version`, and
+/// each earlier minor release is run through its rustup toolchain
+pub fn assemble(version: &str, rustc: &str) -> Result<String, String> {
+    let current: u16 = version
+        .split('.')
+        .nth(1)
+        .and_then(|minor| minor.parse().ok())
+        .ok_or_else(|| format!("unable to parse minor version from '{version}'"))?;
+
+    // Every triple, with the rendered target info for each minor version
+    let mut history = BTreeMap::<String, Vec<(u16, Option<String>)>>::new();
+
+    for minor in FIRST_MINOR..=current {
+        let toolchain = (minor != current).then(|| format!("+1.{minor}"));
+        let rustc = if toolchain.is_some() { "rustc" } else { rustc };
+
+        let targets = run(rustc, toolchain.as_deref(), &["--print", "target-list"])?;
+
+        let infos = std::thread::scope(|s| {
+            let handles: Vec<_> = targets
+                .lines()
+                .map(|target| {
+                    let toolchain = toolchain.as_deref();
+                    s.spawn(move || {
+                        let cfgs = run(rustc, toolchain, &["--target", target, "--print", "cfg"]);
+                        let unstable = crate::unstable_cfgs(rustc, toolchain, target);
+                        let metadata = crate::target_metadata(rustc, toolchain, target);
+                        (target, cfgs.map(|cfgs| (cfgs, unstable, metadata)))
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        for (target, cfgs) in infos {
+            let info = match cfgs {
+                Ok((cfgs, unstable, metadata)) => {
+                    Some(render(target, &cfgs, &unstable, &metadata)?)
+                }
+                Err(err) => {
+                    // Some targets have been listed despite not being usable
+                    // on a stable toolchain, treat them as not existing
+                    eprintln!("skipping {target} on 1.{minor}: {err}");
+                    None
+                }
+            };
+
+            history
+                .entry(target.to_owned())
+                .or_default()
+                .push((minor, info));
+        }
+
+        // Targets that were removed in this version
+        for changes in history.values_mut() {
+            if changes.last().is_some_and(|(m, _)| *m != minor) {
+                changes.push((minor, None));
+            }
+        }
+    }
+
+    let mut out = String::with_capacity(1024 * 1024);
+
+    out.push_str(
+        "/*
+ *
+ * AUTO-GENERATED BY ./update
+ * DO NOT MODIFY
+ *
+ * cargo run --manifest-path update/Cargo.toml -- --history
+ */
+
+#![allow(non_upper_case_globals, non_camel_case_types)]
+
+use super::*;
+use crate::expr::RustcVersion;
+
+const fn v(minor: u16) -> RustcVersion {
+    RustcVersion {
+        major: 1,
+        minor,
+        patch: 0,
+    }
+}
+
+",
+    );
+
+    writeln!(
+        out,
+        "pub(crate) const FIRST_VERSION: RustcVersion = v({FIRST_MINOR});"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub(crate) const LAST_VERSION: RustcVersion = v({current});"
+    )
+    .unwrap();
+    out.push_str("\npub const BUILTIN_HISTORY: &[TargetHistory] = &[\n");
+
+    for (triple, changes) in history {
+        writeln!(
+            out,
+            "TargetHistory {{ triple: Triple::new_const(\"{triple}\"), changes: &["
+        )
+        .unwrap();
+
+        // Only record the versions where the target actually changed
+        let mut prev: Option<&Option<String>> = None;
+        for (minor, info) in &changes {
+            if prev == Some(info) || (prev.is_none() && info.is_none()) {
+                continue;
+            }
+            prev = Some(info);
+
+            match info {
+                Some(info) => writeln!(
+                    out,
+                    "TargetChange {{ since: v({minor}), info: Some({info}) }},"
+                ),
+                None => writeln!(out, "TargetChange {{ since: v({minor}), info: None }},"),
+            }
+            .unwrap();
+        }
+
+        writeln!(out, "] }},").unwrap();
+    }
+
+    out.push_str("];\n");
+
+    Ok(out)
+}
+
+fn run(rustc: &str, toolchain: Option<&str>, args: &[&str]) -> Result<String, String> {
+    let mut cmd = Command::new(rustc);
+    cmd.args(toolchain).args(args);
+
+    let output = cmd
+        .output()
+        .map_err(|e| format!("failed to run {cmd:?}: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{cmd:?} returned {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    String::from_utf8(output.stdout).map_err(|e| format!("unable to parse stdout: {e}"))
+}
+
+/// Renders the output of `--print cfg` as a `TargetInfo`, without relying on
+/// the named constants in `builtins.rs`, as older releases have values that
+/// no longer exist
+fn render(triple: &str, cfgs: &str, unstable: &[String], metadata: &str) -> Result<String, String> {
+    let mut values = BTreeMap::<&str, Vec<&str>>::new();
+    let mut flags = Vec::new();
+
+    for line in cfgs.lines().chain(unstable.iter().map(String::as_str)) {
+        match line.split_once('=') {
+            Some((key, val)) => values.entry(key).or_default().push(val.trim_matches('"')),
+            None => flags.push(line),
+        }
+    }
+
+    let one = |key: &str| {
+        values
+            .get(key)
+            .and_then(|vals| vals.first().copied())
+            .filter(|val| !val.is_empty())
+    };
+    let required = |key: &str| one(key).ok_or_else(|| format!("{triple} had no {key}"));
+    let opt = |kind: &str, key: &str| match one(key) {
+        Some(val) if key != "target_os" || val != "none" => {
+            format!("Some({kind}::new_const(\"{val}\"))")
+        }
+        _ => "None".to_owned(),
+    };
+
+    let mut families = values.get("target_family").cloned().unwrap_or_default();
+    families.sort_unstable();
+
+    let mut target_features = values.get("target_feature").cloned().unwrap_or_default();
+    target_features.sort_unstable();
+
+    // Sorted the same as `HasAtomic`, integer sizes before pointers
+    let has_atomics = |key: &str| {
+        let mut has_atomics = values.get(key).cloned().unwrap_or_default();
+        // nightly has renamed equal_alignment to primitive_alignment
+        if key == "target_has_atomic_equal_alignment" {
+            has_atomics.extend(
+                values
+                    .get("target_has_atomic_primitive_alignment")
+                    .into_iter()
+                    .flatten(),
+            );
+        }
+        has_atomics.sort_unstable_by_key(|val| (*val == "ptr", val.parse::<u16>().ok()));
+        has_atomics
+    };
+
+    let flag = |flag: &str| flags.contains(&flag);
+    let reliable_floats = if flags.iter().any(|f| f.starts_with("target_has_reliable_")) {
+        format!(
+            "ReliableFloats {{ f16: {}, f16_math: {}, f128: {}, f128_math: {} }}",
+            flag("target_has_reliable_f16"),
+            flag("target_has_reliable_f16_math"),
+            flag("target_has_reliable_f128"),
+            flag("target_has_reliable_f128_math"),
+        )
+    } else {
+        "ReliableFloats::NONE".to_owned()
+    };
+
+    let list = |kind: &str, vals: &[&str], element: &dyn Fn(&str) -> String| {
+        if vals.is_empty() {
+            return format!("{kind}::new_const(&[])");
+        }
+
+        let elements: Vec<_> = vals.iter().map(|val| element(val)).collect();
+        format!("{kind}::new_const(const {{ &[{}] }})", elements.join(", "))
+    };
+
+    let has_atomic = |val: &str| match val {
+        "ptr" => "HasAtomic::Pointer".to_owned(),
+        size => format!("HasAtomic::IntegerSize({size})"),
+    };
+
+    Ok(format!(
+        "TargetInfo {{
+        triple: Triple::new_const(\"{triple}\"),
+        os: {os},
+        abi: {abi},
+        arch: Arch::new_const(\"{arch}\"),
+        env: {env},
+        vendor: {vendor},
+        families: {families},
+        pointer_width: {width},
+        endian: Endian::{endian},
+        has_atomics: {has_atomics},
+        has_atomics_load_store: {has_atomics_load_store},
+        has_atomics_equal_alignment: {has_atomics_equal_alignment},
+        panic: Panic::new_const(\"{panic}\"),
+        target_features: {target_features},
+        thread_local: {thread_local},
+        reliable_floats: {reliable_floats},
+        metadata: {metadata},
+    }}",
+        os = opt("Os", "target_os"),
+        abi = opt("Abi", "target_abi"),
+        arch = required("target_arch")?,
+        env = opt("Env", "target_env"),
+        vendor = opt("Vendor", "target_vendor"),
+        families = list("Families", &families, &|fam| format!(
+            "Family::new_const(\"{fam}\")"
+        )),
+        width = required("target_pointer_width")?,
+        endian = required("target_endian")?,
+        has_atomics = list("HasAtomics", &has_atomics("target_has_atomic"), &has_atomic),
+        has_atomics_load_store = list(
+            "HasAtomics",
+            &has_atomics("target_has_atomic_load_store"),
+            &has_atomic
+        ),
+        has_atomics_equal_alignment = list(
+            "HasAtomics",
+            &has_atomics("target_has_atomic_equal_alignment"),
+            &has_atomic
+        ),
+        thread_local = flag("target_thread_local"),
+        panic = one("panic").unwrap_or("unwind"),
+        target_features = list("TargetFeatures", &target_features, &|feat| format!(
+            "TargetFeature::new_const(\"{feat}\")"
+        )),
+    ))
+}
@@ -1,5 +1,7 @@
 use std::{fmt::Write, process::Command};
 
+mod history;
+
 const BUILTINS: &str = "src/targets/builtins.rs";
 const README: &str = "README.md";
 
@@ -63,6 +65,7 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
     let mut has_atomics: Vec<HasAtomicElement> = Vec::new();
     let mut has_atomic_groups: Vec<Vec<HasAtomicElement>> = Vec::new();
     let mut panics: Vec<String> = Vec::new();
+    let mut target_features: Vec<String> = Vec::new();
     //let mut relocation_models: Vec<String> = Vec::new();
 
     for target in targets.lines() {
@@ -91,8 +94,8 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
         }
 
         let kv = String::from_utf8(output.stdout).unwrap();
+        let unstable = unstable_cfgs(&rustc, None, target);
 
-        //let mut num_feats = 0;
         let mut abi = None;
         let mut arch = None;
         let mut endian = None;
@@ -104,11 +107,26 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
         let mut panic = None;
         //let mut relocation_model = None;
         let mut has_atomic_group = Vec::new();
+        let mut has_atomic_load_store_group = Vec::new();
+        let mut has_atomic_equal_alignment_group = Vec::new();
+        let mut target_feature_group = Vec::new();
+        let mut thread_local = false;
+        let mut reliable_floats = Vec::new();
 
-        for line in kv.lines() {
+        for line in kv.lines().chain(unstable.iter().map(String::as_str)) {
             let eq_ind = line.find('=');
             match eq_ind {
                 None => {
+                    match line {
+                        "target_thread_local" => thread_local = true,
+                        "target_has_reliable_f16"
+                        | "target_has_reliable_f16_math"
+                        | "target_has_reliable_f128"
+                        | "target_has_reliable_f128_math" => {
+                            reliable_floats.push(line.trim_start_matches("target_has_reliable_"));
+                        }
+                        _ => {}
+                    }
                     continue;
                 }
                 Some(i) => {
@@ -137,24 +155,18 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
                             }
                         }
                         "target_family" => family_group.push(val.to_owned()),
-                        "target_feature" => {
-
-                            // num_feats += 1;
-                            // write!(
-                            //     features,
-                            //     "Features::{} as u32 | ",
-                            //     match val {
-                            //         "sse4.1" => "sse41",
-                            //         "sse4.2" => "sse42",
-                            //         "crt-static" => "crt_static",
-                            //         passthrough => passthrough,
-                            //     }
-                            // )
-                            // .unwrap();
-                        }
+                        "target_feature" => target_feature_group.push(val.to_owned()),
                         "target_has_atomic" => {
                             has_atomic_group.push(HasAtomicElement::new(val));
                         }
+                        "target_has_atomic_load_store" => {
+                            has_atomic_load_store_group.push(HasAtomicElement::new(val));
+                        }
+                        // nightly has renamed equal_alignment to primitive_alignment
+                        "target_has_atomic_equal_alignment"
+                        | "target_has_atomic_primitive_alignment" => {
+                            has_atomic_equal_alignment_group.push(HasAtomicElement::new(val));
+                        }
                         "target_os" => {
                             if val != "none" {
                                 os = Some(val)
@@ -167,12 +179,7 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
                             }
                         }
                         // unstable
-                        "relocation_model"
-                        | "target_has_atomic_equal_alignment"
-                        | "target_has_atomic_load_store"
-                        | "target_has_atomic_primitive_alignment"
-                        | "target_object_format"
-                        | "fmt_debug" => {
+                        "relocation_model" | "target_object_format" | "fmt_debug" | "sanitize" => {
                             //relocation_model = Some(val),
                         }
                         _ => panic!("unknown key: {line}"),
@@ -240,6 +247,34 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
             "HasAtomics",
             "atomic_",
         );
+        let has_atomics_load_store_str = insert_group(
+            has_atomic_load_store_group,
+            &mut has_atomics,
+            &mut has_atomic_groups,
+            "HasAtomics",
+            "atomic_",
+        );
+        let has_atomics_equal_alignment_str = insert_group(
+            has_atomic_equal_alignment_group,
+            &mut has_atomics,
+            &mut has_atomic_groups,
+            "HasAtomics",
+            "atomic_",
+        );
+
+        // Unlike the other groups, there are too many target features to
+        // give each unique set its own named constant, so use an inline
+        // const so the slice can still be borrowed for 'static
+        target_feature_group.sort_unstable();
+        let mut target_features_str = String::from("TargetFeatures::new_const(const { &[");
+        for (idx, feat) in target_feature_group.iter().enumerate() {
+            insert(Some(feat), &mut target_features);
+            if idx > 0 {
+                target_features_str.push_str(", ");
+            }
+            write!(target_features_str, "TargetFeature::{}", to_ident(feat)).unwrap();
+        }
+        target_features_str.push_str("] })");
 
         let print_opt = |kind: &str, opt: Option<&str>| {
             if let Some(val) = opt {
@@ -264,7 +299,13 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
         pointer_width: {width},
         endian: Endian::{endian},
         has_atomics: {has_atomics_str},
+        has_atomics_load_store: {has_atomics_load_store_str},
+        has_atomics_equal_alignment: {has_atomics_equal_alignment_str},
         panic: Panic::{panic},
+        target_features: {target_features_str},
+        thread_local: {thread_local},
+        reliable_floats: {reliable_floats},
+        metadata: {metadata},
     }},",
             triple = target,
             os = print_opt("Os", os),
@@ -275,6 +316,8 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
             width = width.expect("target had no pointer_width"),
             endian = endian.expect("target had no endian"),
             panic = panic.expect("target had no panic"),
+            reliable_floats = reliable_floats_str(&reliable_floats),
+            metadata = target_metadata(&rustc, None, target),
             //rel_model = print_opt("RelocationModel", relocation_model),
         )
         .unwrap();
@@ -306,17 +349,118 @@ fn assemble(version: &str, rustc: String) -> Result<String, String> {
         has_atomic_groups,
     );
     write_impls(&mut out, "Panic", panics);
+    write_impls(&mut out, "TargetFeature", target_features);
     //write_impls(&mut out, "RelocationModel", relocation_models);
 
     Ok(out)
 }
 
+/// The unstable cfgs that are recorded in `TargetInfo`
+const UNSTABLE_CFGS: &[&str] = &[
+    "target_has_atomic_load_store",
+    "target_has_atomic_equal_alignment",
+    // nightly has renamed equal_alignment to primitive_alignment
+    "target_has_atomic_primitive_alignment",
+    "target_thread_local",
+    "target_has_reliable_f16",
+    "target_has_reliable_f16_math",
+    "target_has_reliable_f128",
+    "target_has_reliable_f128_math",
+];
+
+/// Retrieves the lines of `--print cfg` for the unstable cfgs in
+/// [`UNSTABLE_CFGS`], which are only printed with `RUSTC_BOOTSTRAP`
+///
+/// The rest of the output isn't used, as it also contains unstable target
+/// features, as well as cfgs that were unstable in older releases, eg.
+/// `target_abi`. Releases that fail to print them are treated as not having
+/// any of them.
+fn unstable_cfgs(rustc: &str, toolchain: Option<&str>, target: &str) -> Vec<String> {
+    let output = Command::new(rustc)
+        .args(toolchain)
+        .args(["--target", target, "--print", "cfg"])
+        .env("RUSTC_BOOTSTRAP", "1")
+        .output();
+
+    let cfgs = match output {
+        Ok(output) if output.status.success() => String::from_utf8(output.stdout).unwrap(),
+        _ => return Vec::new(),
+    };
+
+    cfgs.lines()
+        .filter(|line| UNSTABLE_CFGS.contains(&line.split_once('=').map_or(*line, |(key, _)| key)))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Renders the `target_has_reliable_*` cfgs of a target as `ReliableFloats`,
+/// where each float is the suffix of its cfg, eg. `f16_math`
+fn reliable_floats_str(floats: &[&str]) -> String {
+    if floats.is_empty() {
+        return "ReliableFloats::NONE".to_owned();
+    }
+
+    let has = |float: &str| floats.contains(&float);
+    format!(
+        "ReliableFloats {{ f16: {}, f16_math: {}, f128: {}, f128_math: {} }}",
+        has("f16"),
+        has("f16_math"),
+        has("f128"),
+        has("f128_math"),
+    )
+}
+
+/// Renders the `metadata` of the target as a `TargetMetadata`
+///
+/// The metadata is only available from `--print target-spec-json`, which is
+/// unstable, so this relies on `RUSTC_BOOTSTRAP`. Releases that fail to print
+/// it, or that don't have metadata, are treated as having unknown metadata.
+fn target_metadata(rustc: &str, toolchain: Option<&str>, target: &str) -> String {
+    let output = Command::new(rustc)
+        .args(toolchain)
+        .args(["-Z", "unstable-options", "--print", "target-spec-json"])
+        .args(["--target", target])
+        .env("RUSTC_BOOTSTRAP", "1")
+        .output();
+
+    let json = match output {
+        Ok(output) if output.status.success() => String::from_utf8(output.stdout).unwrap(),
+        _ => return "TargetMetadata::UNKNOWN".to_owned(),
+    };
+
+    let Some(metadata) = json
+        .split_once("\"metadata\": {")
+        .and_then(|(_, rest)| rest.split_once('}'))
+        .map(|(metadata, _)| metadata)
+    else {
+        return "TargetMetadata::UNKNOWN".to_owned();
+    };
+
+    let field = |key: &str| {
+        let value = metadata
+            .split_once(&format!("\"{key}\": "))
+            .and_then(|(_, rest)| rest.split([',', '\n']).next())
+            .map(str::trim);
+
+        match value {
+            None | Some("null") => "None".to_owned(),
+            Some(value) => format!("Some({value})"),
+        }
+    };
+
+    let (tier, std, host_tools) = (field("tier"), field("std"), field("host_tools"));
+    if tier == "None" && std == "None" && host_tools == "None" {
+        return "TargetMetadata::UNKNOWN".to_owned();
+    }
+
+    format!("TargetMetadata {{ tier: {tier}, std: {std}, host_tools: {host_tools} }}")
+}
+
 fn write_impls(out: &mut String, typ: &'static str, builtins: Vec<String>) {
     writeln!(out, "\nimpl super::{typ} {{").unwrap();
 
     for thing in builtins {
-        // Use _ instead of - in identifiers.
-        let ident = thing.replace("-", "_");
+        let ident = to_ident(&thing);
         writeln!(
             out,
             "pub const {ident}: {typ} = {typ}::new_const(\"{thing}\");"
@@ -337,14 +481,19 @@ trait GroupElement: Clone + Eq + Ord {
 
 impl GroupElement for String {
     fn value_expr(&self) -> String {
-        self.clone()
+        to_ident(self)
     }
 
     fn id_str(&self) -> String {
-        self.clone()
+        to_ident(self)
     }
 }
 
+/// Use _ instead of - and . in identifiers, eg. `sse4.1` => `sse4_1`
+fn to_ident(s: &str) -> String {
+    s.replace(['-', '.'], "_")
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 enum HasAtomicElement {
     IntegerSize(u16),
@@ -466,8 +615,15 @@ fn assemble_readme(rustc_version: &str) -> Result<String, String> {
 fn finalize(builtins: String, readme: String) -> Result<(), String> {
     std::fs::write(BUILTINS, builtins).map_err(|e| format!("failed to write {BUILTINS}: {e}"))?;
 
+    format(BUILTINS)?;
+
+    std::fs::write(README, readme).map_err(|e| format!("failed to write {README}: {e}"))?;
+    Ok(())
+}
+
+fn format(path: &str) -> Result<(), String> {
     let status = Command::new("rustfmt")
-        .args(&["--edition", "2018", "src/targets/builtins.rs"])
+        .args(&["--edition", "2018", path])
         .status()
         .map_err(|e| format!("failed to run rustfmt: {e}"))?;
 
@@ -475,7 +631,6 @@ fn finalize(builtins: String, readme: String) -> Result<(), String> {
         return Err(format!("failed to successfully format: {status}"));
     }
 
-    std::fs::write(README, readme).map_err(|e| format!("failed to write {README}: {e}"))?;
     Ok(())
 }
 
@@ -488,6 +643,23 @@ fn main() {
         }
     };
 
+    // The history is only regenerated on request, as it requires a rustup
+    // toolchain for every release since history::FIRST_MINOR
+    if std::env::args().any(|arg| arg == "--history") {
+        let finalized = history::assemble(&rustc_version, &rustc).and_then(|history| {
+            std::fs::write(history::HISTORY, history)
+                .map_err(|e| format!("failed to write {}: {e}", history::HISTORY))?;
+            format(history::HISTORY)
+        });
+
+        if let Err(err) = finalized {
+            eprintln!("error assembling {}: {err}", history::HISTORY);
+            std::process::exit(1);
+        }
+
+        return;
+    }
+
     let mut assembled = String::new();
     let mut readme = String::new();
     std::thread::scope(|s| {